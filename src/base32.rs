@@ -0,0 +1,164 @@
+// rawstring::base32
+
+use crate::{FromBase32Error, RawStr, RawString};
+
+const RFC4648: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const RFC4648_HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl RawStr {
+	/// Encodes the string as Base32 with the given `alphabet`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Base32Alphabet, RawStr};
+	/// let raw = RawStr::new(b"\xFFhi");
+	/// assert_eq!(raw.to_base32(Base32Alphabet::Rfc4648 { padding: true }), "75UGS===");
+	/// assert_eq!(raw.to_base32(Base32Alphabet::Rfc4648Hex { padding: false }), "VTK6I");
+	/// assert_eq!(raw.to_base32(Base32Alphabet::Crockford), "ZXM6J");
+	/// ```
+	#[must_use]
+	pub fn to_base32(&self, alphabet: Base32Alphabet) -> String {
+		let symbols = alphabet.symbols();
+		let mut out = String::with_capacity(self.len().div_ceil(5) * 8);
+		for chunk in self.0.chunks(5) {
+			let mut group = [0; 5];
+			group[..chunk.len()].copy_from_slice(chunk);
+			let bits = u64::from_be_bytes([0, 0, 0, group[0], group[1], group[2], group[3], group[4]]);
+
+			// each byte spreads over the symbols covering its bits
+			let len = (chunk.len() * 8).div_ceil(5);
+			for i in 0..len {
+				let value = (bits >> (35 - 5 * i)) & 0x1F;
+				out.push(char::from(symbols[value as usize]));
+			}
+			if alphabet.padding() {
+				out.extend(std::iter::repeat_n('=', 8 - len));
+			}
+		}
+		out
+	}
+}
+
+impl RawString {
+	/// Decodes a Base32 string with the given `alphabet`.
+	///
+	/// With [`Rfc4648`](Base32Alphabet::Rfc4648) and [`Rfc4648Hex`](Base32Alphabet::Rfc4648Hex),
+	/// symbols must be uppercase, and padding must be present exactly when it is enabled.
+	/// With [`Crockford`](Base32Alphabet::Crockford), symbols are case-insensitive,
+	/// `I` and `L` are read as `1`, `O` as `0`, and hyphens are ignored.
+	///
+	/// # Errors
+	/// Returns a [`FromBase32Error`] if `encoded` is not valid Base32 for `alphabet`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Base32Alphabet, FromBase32Error, RawString};
+	/// let raw = RawString::from_base32("75UGS===", Base32Alphabet::Rfc4648 { padding: true }).unwrap();
+	/// assert_eq!(raw.as_ref(), b"\xFFhi");
+	///
+	/// let id = RawString::from_base32("zxm-6j", Base32Alphabet::Crockford).unwrap();
+	/// assert_eq!(id.as_ref(), b"\xFFhi");
+	///
+	/// assert_eq!(
+	///     RawString::from_base32("75UG8", Base32Alphabet::Rfc4648 { padding: false }),
+	///     Err(FromBase32Error::InvalidSymbol { index: 4, byte: b'8' }),
+	/// );
+	/// assert_eq!(
+	///     RawString::from_base32("75U", Base32Alphabet::Rfc4648 { padding: false }),
+	///     Err(FromBase32Error::InvalidLength),
+	/// );
+	/// ```
+	pub fn from_base32<B>(encoded: &B, alphabet: Base32Alphabet) -> Result<Self, FromBase32Error>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let mut encoded = encoded.as_ref();
+		if alphabet.padding() {
+			if encoded.len() % 8 != 0 {
+				return Err(FromBase32Error::InvalidLength);
+			}
+			let pad = encoded.iter().rev().take_while(|&&b| b == b'=').count();
+			encoded = &encoded[..encoded.len() - pad];
+			// padding completes the last group only
+			if pad >= 8 || !matches!(encoded.len() % 8, 0 | 2 | 4 | 5 | 7) || (pad > 0) != (encoded.len() % 8 != 0) {
+				return Err(FromBase32Error::InvalidLength);
+			}
+		}
+
+		let mut out = Vec::with_capacity(encoded.len() * 5 / 8);
+		let mut buffer: u16 = 0;
+		let mut bits = 0;
+		let mut symbols = 0;
+		for (index, &byte) in encoded.iter().enumerate() {
+			if alphabet == Base32Alphabet::Crockford && byte == b'-' {
+				continue;
+			}
+			let value = alphabet.decode(byte).ok_or(FromBase32Error::InvalidSymbol { index, byte })?;
+			buffer = (buffer << 5) | u16::from(value);
+			bits += 5;
+			symbols += 1;
+			if bits >= 8 {
+				bits -= 8;
+				out.push((buffer >> bits) as u8);
+				buffer &= (1 << bits) - 1;
+			}
+		}
+		if !matches!(symbols % 8, 0 | 2 | 4 | 5 | 7) {
+			return Err(FromBase32Error::InvalidLength);
+		}
+		Ok(Self(out))
+	}
+}
+
+/// A Base32 alphabet.
+///
+/// Used by [`RawStr::to_base32`] and [`RawString::from_base32`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Base32Alphabet {
+	/// The standard alphabet of RFC 4648, `A`–`Z` then `2`–`7`.
+	Rfc4648 {
+		/// Whether the output is padded with `=` to a multiple of eight symbols.
+		padding: bool,
+	},
+	/// The "extended hex" alphabet of RFC 4648, `0`–`9` then `A`–`V`,
+	/// which preserves the sort order of the encoded data.
+	Rfc4648Hex {
+		/// Whether the output is padded with `=` to a multiple of eight symbols.
+		padding: bool,
+	},
+	/// Douglas Crockford's alphabet, `0`–`9` then the uppercase letters except `I`, `L`, `O` and `U`,
+	/// without padding.
+	Crockford,
+}
+
+impl Base32Alphabet {
+	#[inline]
+	fn symbols(self) -> &'static [u8; 32] {
+		match self {
+			Self::Rfc4648 { .. } => RFC4648,
+			Self::Rfc4648Hex { .. } => RFC4648_HEX,
+			Self::Crockford => CROCKFORD,
+		}
+	}
+
+	#[inline]
+	fn padding(self) -> bool {
+		match self {
+			Self::Rfc4648 { padding } | Self::Rfc4648Hex { padding } => padding,
+			Self::Crockford => false,
+		}
+	}
+
+	/// Returns the value of a symbol.
+	#[inline]
+	fn decode(self, symbol: u8) -> Option<u8> {
+		let symbol = match (self, symbol.to_ascii_uppercase()) {
+			(Self::Crockford, b'I' | b'L') => b'1',
+			(Self::Crockford, b'O') => b'0',
+			(Self::Crockford, upper) => upper,
+			_ => symbol,
+		};
+		self.symbols().iter().position(|&s| s == symbol).map(|i| i as u8)
+	}
+}
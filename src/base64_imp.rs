@@ -0,0 +1,54 @@
+// rawstring::base64_imp
+
+use base64::{DecodeError, Engine};
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Encodes the string as Base64 with the given `engine`,
+	/// which sets the alphabet and padding used.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+	///
+	/// let raw = RawStr::new(b"\xFF\xFEkey?");
+	/// assert_eq!(raw.to_base64(&STANDARD), "//5rZXk/");
+	/// assert_eq!(raw.to_base64(&URL_SAFE_NO_PAD), "__5rZXk_");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_base64<E>(&self, engine: &E) -> String
+	where
+		E: Engine
+	{
+		engine.encode(&self.0)
+	}
+}
+
+impl RawString {
+	/// Decodes a Base64 string with the given `engine`,
+	/// which must be configured like the one used for encoding.
+	///
+	/// # Errors
+	/// Returns a [`DecodeError`] if `encoded` is not valid Base64 for `engine`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// use base64::engine::general_purpose::STANDARD;
+	///
+	/// let raw = RawString::from_base64(RawStr::new("//5rZXk/"), &STANDARD).unwrap();
+	/// assert_eq!(raw.as_ref(), b"\xFF\xFEkey?");
+	/// assert!(RawString::from_base64("not base64!", &STANDARD).is_err());
+	/// ```
+	#[inline]
+	pub fn from_base64<B, E>(encoded: &B, engine: &E) -> Result<Self, DecodeError>
+	where
+		B: ?Sized + AsRef<[u8]>,
+		E: Engine
+	{
+		engine.decode(encoded.as_ref()).map(Self)
+	}
+}
@@ -0,0 +1,112 @@
+// rawstring::binary
+
+use crate::{RawStr, utf8};
+
+impl RawStr {
+	/// Returns `true` if the string looks like binary data rather than text,
+	/// using the default [`BinaryThresholds`].
+	///
+	/// See [`is_probably_binary_with`](Self::is_probably_binary_with).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(!RawStr::new("plain text\r\n\twith tabs\n").is_probably_binary());
+	/// assert!(!RawStr::new(b"Latin-1 caf\xE9 cr\xE8me").is_probably_binary());
+	/// assert!(!RawStr::new(b"\x1B[1mbold\x1B[0m\n").is_probably_binary());
+	/// assert!(RawStr::new(b"\x7FELF\x02\x01\x01\0\0\0").is_probably_binary());
+	/// assert!(RawStr::new(b"\x89PNG\r\n\x1A\n\x8A\xF3\xC1\x94\xFE\xB0").is_probably_binary());
+	/// assert!(!RawStr::new("").is_probably_binary());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn is_probably_binary(&self) -> bool {
+		self.is_probably_binary_with(BinaryThresholds::default())
+	}
+
+	/// Returns `true` if the string looks like binary data rather than text,
+	/// according to the given thresholds.
+	///
+	/// The string is considered binary if any of these hold:
+	/// - it contains a NUL byte, unless [`allow_nul`](BinaryThresholds::allow_nul) is set,
+	/// - the proportion of control bytes is above [`max_control_ratio`](BinaryThresholds::max_control_ratio),
+	/// - the proportion of bytes in invalid UTF-8 sequences is above
+	///   [`max_invalid_ratio`](BinaryThresholds::max_invalid_ratio).
+	///
+	/// Control bytes are the ASCII control characters and `DEL`, except for
+	/// the whitespace characters `\t`, `\n`, `\x0B`, `\x0C` and `\r`, and `ESC`,
+	/// which is common in terminal output. NUL bytes count as control bytes.
+	/// The empty string is never binary.
+	///
+	/// To classify a large buffer cheaply, pass only its first few kilobytes.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{BinaryThresholds, RawStr};
+	/// let utf16 = RawStr::new(b"h\0e\0l\0l\0o\0");
+	/// assert!(utf16.is_probably_binary());
+	///
+	/// let lenient = BinaryThresholds { allow_nul: true, max_control_ratio: 0.5, ..Default::default() };
+	/// assert!(!utf16.is_probably_binary_with(lenient));
+	///
+	/// let strict = BinaryThresholds { max_invalid_ratio: 0.0, ..Default::default() };
+	/// assert!(RawStr::new(b"caf\xE9").is_probably_binary_with(strict));
+	/// ```
+	#[must_use]
+	pub fn is_probably_binary_with(&self, thresholds: BinaryThresholds) -> bool {
+		if self.is_empty() {
+			return false;
+		}
+		if !thresholds.allow_nul && crate::bytes::find_byte(&self.0, 0).is_some() {
+			return true;
+		}
+
+		let mut control = 0;
+		let mut invalid = 0;
+		let mut i = 0;
+		while let Some((c, len)) = utf8::decode_first(&self.0[i..]) {
+			match c {
+				Some(c) if is_control(c) => control += 1,
+				Some(_) => {}
+				None => invalid += len,
+			}
+			i += len;
+		}
+
+		let total = self.len() as f64;
+		control as f64 / total > thresholds.max_control_ratio
+			|| invalid as f64 / total > thresholds.max_invalid_ratio
+	}
+}
+
+/// The thresholds used by [`RawStr::is_probably_binary_with`] to tell binary data from text.
+///
+/// The ratios are proportions of the bytes of the string, between `0.0` and `1.0`.
+/// The defaults suit text in UTF-8 or in single-byte legacy encodings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BinaryThresholds {
+	/// Whether NUL bytes are allowed in text. Defaults to `false`,
+	/// so that a single NUL byte makes the string binary.
+	pub allow_nul: bool,
+	/// The highest proportion of control bytes allowed in text. Defaults to `0.1`.
+	pub max_control_ratio: f64,
+	/// The highest proportion of bytes in invalid UTF-8 sequences allowed in text. Defaults to `0.3`.
+	pub max_invalid_ratio: f64,
+}
+
+impl Default for BinaryThresholds {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			allow_nul: false,
+			max_control_ratio: 0.1,
+			max_invalid_ratio: 0.3,
+		}
+	}
+}
+
+/// Returns `true` if `c` is a control character that is unusual in text.
+#[inline]
+fn is_control(c: char) -> bool {
+	matches!(c, '\0'..='\x08' | '\x0E'..='\x1A' | '\x1C'..='\x1F' | '\x7F')
+}
@@ -0,0 +1,102 @@
+// rawstring::bom
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns the byte order mark the string starts with, if any.
+	///
+	/// A UTF-32 little endian mark is recognized before the UTF-16 little endian one it starts with.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Bom, RawStr};
+	/// assert_eq!(RawStr::new(b"\xEF\xBB\xBFdata").detect_bom(), Some(Bom::Utf8));
+	/// assert_eq!(RawStr::new(b"\xFF\xFEd\0").detect_bom(), Some(Bom::Utf16Le));
+	/// assert_eq!(RawStr::new(b"\xFF\xFE\0\0").detect_bom(), Some(Bom::Utf32Le));
+	/// assert_eq!(RawStr::new("data").detect_bom(), None);
+	/// ```
+	#[must_use]
+	pub fn detect_bom(&self) -> Option<Bom> {
+		[Bom::Utf8, Bom::Utf32Le, Bom::Utf32Be, Bom::Utf16Le, Bom::Utf16Be]
+			.into_iter()
+			.find(|bom| self.0.starts_with(bom.as_bytes()))
+	}
+
+	/// Returns the string with its leading byte order mark removed, if it has one.
+	///
+	/// See [`detect_bom`](Self::detect_bom).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xEF\xBB\xBFname,\xFF").strip_bom(), b"name,\xFF");
+	/// assert_eq!(RawStr::new("name").strip_bom(), "name");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn strip_bom(&self) -> &RawStr {
+		let len = self.detect_bom().map_or(0, Bom::len);
+		RawStr::from_bytes(&self.0[len..])
+	}
+}
+
+impl RawString {
+	/// Removes the leading byte order mark of the string in place, returning it.
+	///
+	/// See [`RawStr::detect_bom`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Bom, RawString};
+	/// let mut raw = RawString::from(b"\xFE\xFF\0a".as_slice());
+	/// assert_eq!(raw.strip_bom_in_place(), Some(Bom::Utf16Be));
+	/// assert_eq!(raw.as_ref(), b"\0a");
+	/// assert_eq!(raw.strip_bom_in_place(), None);
+	/// ```
+	#[inline]
+	pub fn strip_bom_in_place(&mut self) -> Option<Bom> {
+		let bom = RawStr::from_bytes(&self.0).detect_bom()?;
+		self.0.drain(..bom.len());
+		Some(bom)
+	}
+}
+
+/// A byte order mark, identifying the encoding of the text it starts.
+///
+/// Returned by [`RawStr::detect_bom`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Bom {
+	/// UTF-8: `EF BB BF`.
+	Utf8,
+	/// UTF-16 little endian: `FF FE`.
+	Utf16Le,
+	/// UTF-16 big endian: `FE FF`.
+	Utf16Be,
+	/// UTF-32 little endian: `FF FE 00 00`.
+	Utf32Le,
+	/// UTF-32 big endian: `00 00 FE FF`.
+	Utf32Be,
+}
+
+impl Bom {
+	/// Returns the bytes of the byte order mark.
+	#[inline]
+	#[must_use]
+	pub const fn as_bytes(self) -> &'static [u8] {
+		match self {
+			Self::Utf8 => b"\xEF\xBB\xBF",
+			Self::Utf16Le => b"\xFF\xFE",
+			Self::Utf16Be => b"\xFE\xFF",
+			Self::Utf32Le => b"\xFF\xFE\0\0",
+			Self::Utf32Be => b"\0\0\xFE\xFF",
+		}
+	}
+
+	/// Returns the length of the byte order mark in bytes.
+	#[inline]
+	#[must_use]
+	#[allow(clippy::len_without_is_empty)]
+	pub const fn len(self) -> usize {
+		self.as_bytes().len()
+	}
+}
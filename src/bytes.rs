@@ -0,0 +1,217 @@
+// rawstring::bytes
+
+// Byte searching primitives.
+// These use `memchr` when the feature is enabled and fall back to plain loops otherwise.
+
+/// Returns the offset of the first occurrence of `needle` in `haystack`.
+#[inline]
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memmem::find(haystack, needle)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		if needle.is_empty() {
+			return Some(0);
+		}
+		haystack.windows(needle.len()).position(|window| window == needle)
+	}
+}
+
+/// Returns the offset of the last occurrence of `needle` in `haystack`.
+#[inline]
+pub(crate) fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memmem::rfind(haystack, needle)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		if needle.is_empty() {
+			return Some(haystack.len());
+		}
+		haystack.windows(needle.len()).rposition(|window| window == needle)
+	}
+}
+
+/// Returns the offset of the first occurrence of `b1` in `haystack`.
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], b1: u8) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memchr(b1, haystack)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		haystack.iter().position(|&b| b == b1)
+	}
+}
+
+/// Returns the offset of the last occurrence of `b1` in `haystack`.
+#[inline]
+pub(crate) fn rfind_byte(haystack: &[u8], b1: u8) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memrchr(b1, haystack)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		haystack.iter().rposition(|&b| b == b1)
+	}
+}
+
+/// Returns the offset of the first occurrence of `b1` or `b2` in `haystack`.
+#[inline]
+pub(crate) fn find_byte2(haystack: &[u8], b1: u8, b2: u8) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memchr2(b1, b2, haystack)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		haystack.iter().position(|&b| b == b1 || b == b2)
+	}
+}
+
+/// Returns the offset of the last occurrence of `b1` or `b2` in `haystack`.
+#[inline]
+pub(crate) fn rfind_byte2(haystack: &[u8], b1: u8, b2: u8) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memrchr2(b1, b2, haystack)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		haystack.iter().rposition(|&b| b == b1 || b == b2)
+	}
+}
+
+/// Returns the offset of the first occurrence of `b1`, `b2` or `b3` in `haystack`.
+#[inline]
+pub(crate) fn find_byte3(haystack: &[u8], b1: u8, b2: u8, b3: u8) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memchr3(b1, b2, b3, haystack)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		haystack.iter().position(|&b| b == b1 || b == b2 || b == b3)
+	}
+}
+
+/// Returns the offset of the last occurrence of `b1`, `b2` or `b3` in `haystack`.
+#[inline]
+pub(crate) fn rfind_byte3(haystack: &[u8], b1: u8, b2: u8, b3: u8) -> Option<usize> {
+	#[cfg(feature = "memchr")]
+	{
+		memchr::memrchr3(b1, b2, b3, haystack)
+	}
+	#[cfg(not(feature = "memchr"))]
+	{
+		haystack.iter().rposition(|&b| b == b1 || b == b2 || b == b3)
+	}
+}
+
+/// A set of bytes, stored as a 256-bit table.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ByteSet([u64; 4]);
+
+impl ByteSet {
+	#[inline]
+	pub(crate) fn new(bytes: &[u8]) -> Self {
+		let mut table = [0; 4];
+		for &b in bytes {
+			table[usize::from(b >> 6)] |= 1 << (b & 63);
+		}
+		Self(table)
+	}
+
+	#[inline]
+	pub(crate) fn contains(&self, b: u8) -> bool {
+		self.0[usize::from(b >> 6)] & (1 << (b & 63)) != 0
+	}
+}
+
+/// Returns the offset of the first byte of `haystack` that is in `set`.
+#[inline]
+pub(crate) fn find_byteset(haystack: &[u8], set: &[u8]) -> Option<usize> {
+	match *set {
+		[] => None,
+		[b1] => find_byte(haystack, b1),
+		[b1, b2] => find_byte2(haystack, b1, b2),
+		[b1, b2, b3] => find_byte3(haystack, b1, b2, b3),
+		_ => {
+			let set = ByteSet::new(set);
+			haystack.iter().position(|&b| set.contains(b))
+		}
+	}
+}
+
+/// Returns the offset of the last byte of `haystack` that is in `set`.
+#[inline]
+pub(crate) fn rfind_byteset(haystack: &[u8], set: &[u8]) -> Option<usize> {
+	match *set {
+		[] => None,
+		[b1] => rfind_byte(haystack, b1),
+		[b1, b2] => rfind_byte2(haystack, b1, b2),
+		[b1, b2, b3] => rfind_byte3(haystack, b1, b2, b3),
+		_ => {
+			let set = ByteSet::new(set);
+			haystack.iter().rposition(|&b| set.contains(b))
+		}
+	}
+}
+
+/// Returns the offset of the first byte of `haystack` that is not in `set`.
+#[inline]
+pub(crate) fn find_not_byteset(haystack: &[u8], set: &[u8]) -> Option<usize> {
+	let set = ByteSet::new(set);
+	haystack.iter().position(|&b| !set.contains(b))
+}
+
+/// Returns the offset of the last byte of `haystack` that is not in `set`.
+#[inline]
+pub(crate) fn rfind_not_byteset(haystack: &[u8], set: &[u8]) -> Option<usize> {
+	let set = ByteSet::new(set);
+	haystack.iter().rposition(|&b| !set.contains(b))
+}
+
+/// Returns the offset of the first ASCII case-insensitive occurrence of `needle` in `haystack`.
+pub(crate) fn find_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	let Some((&first, _)) = needle.split_first() else {
+		return Some(0);
+	};
+	let last_start = haystack.len().checked_sub(needle.len())?;
+	let (lower, upper) = (first.to_ascii_lowercase(), first.to_ascii_uppercase());
+
+	// jump between candidates for the first byte, then compare the rest
+	let mut position = 0;
+	while position <= last_start {
+		let i = position + find_byte2(&haystack[position..=last_start], lower, upper)?;
+		if haystack[i..i + needle.len()].eq_ignore_ascii_case(needle) {
+			return Some(i);
+		}
+		position = i + 1;
+	}
+	None
+}
+
+/// Returns the offset of the last ASCII case-insensitive occurrence of `needle` in `haystack`.
+pub(crate) fn rfind_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	let Some((&first, _)) = needle.split_first() else {
+		return Some(haystack.len());
+	};
+	let last_start = haystack.len().checked_sub(needle.len())?;
+	let (lower, upper) = (first.to_ascii_lowercase(), first.to_ascii_uppercase());
+
+	let mut end = last_start + 1;
+	while end > 0 {
+		let i = rfind_byte2(&haystack[..end], lower, upper)?;
+		if haystack[i..i + needle.len()].eq_ignore_ascii_case(needle) {
+			return Some(i);
+		}
+		end = i;
+	}
+	None
+}
@@ -0,0 +1,243 @@
+// rawstring::case
+
+use crate::{RawStr, RawString, utf8};
+
+impl RawStr {
+	/// Returns the uppercase equivalent of the string as a new [`RawString`].
+	///
+	/// Valid UTF-8 is mapped with [`str::to_uppercase`], using the full Unicode case mapping,
+	/// so the result may be longer than the input. Invalid bytes are copied unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"stra\xC3\x9Fe \xFF\xFE");
+	/// assert_eq!(raw.to_uppercase().as_ref(), b"STRASSE \xFF\xFE");
+	/// ```
+	#[must_use]
+	pub fn to_uppercase(&self) -> RawString {
+		map_valid(self, str::to_uppercase)
+	}
+
+	/// Returns the lowercase equivalent of the string as a new [`RawString`].
+	///
+	/// Valid UTF-8 is mapped with [`str::to_lowercase`], using the full Unicode case mapping.
+	/// Invalid bytes are copied unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFF\xCE\x9F\xCE\xA3 ID");
+	/// assert_eq!(raw.to_lowercase().as_ref(), b"\xFF\xCE\xBF\xCF\x82 id");
+	/// ```
+	#[must_use]
+	pub fn to_lowercase(&self) -> RawString {
+		map_valid(self, str::to_lowercase)
+	}
+
+	/// Returns a copy of the string with ASCII letters mapped to uppercase,
+	/// leaving all other bytes unchanged.
+	///
+	/// To uppercase in place, use [`make_ascii_uppercase`](slice::make_ascii_uppercase).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"get \xFF/\xC3\xA9").to_ascii_uppercase().as_ref(), b"GET \xFF/\xC3\xA9");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_ascii_uppercase(&self) -> RawString {
+		RawString::from(self.0.to_ascii_uppercase())
+	}
+
+	/// Returns a copy of the string with ASCII letters mapped to lowercase,
+	/// leaving all other bytes unchanged.
+	///
+	/// To lowercase in place, use [`make_ascii_lowercase`](slice::make_ascii_lowercase).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"Content-Type\xFF").to_ascii_lowercase().as_ref(), b"content-type\xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_ascii_lowercase(&self) -> RawString {
+		RawString::from(self.0.to_ascii_lowercase())
+	}
+
+	/// Returns a copy of the string with its first character mapped to uppercase.
+	///
+	/// The rest of the string is left unchanged,
+	/// and so is the whole string if it starts with invalid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xC3\xA9cole \xFF").capitalize().as_ref(), b"\xC3\x89cole \xFF");
+	/// assert_eq!(RawStr::new("ßig").capitalize().as_ref(), "SSig");
+	/// assert_eq!(RawStr::new(b"\xFFabc").capitalize().as_ref(), b"\xFFabc");
+	/// ```
+	#[must_use]
+	pub fn capitalize(&self) -> RawString {
+		let mut out = Vec::with_capacity(self.len());
+		let rest = match utf8::decode_first(&self.0) {
+			Some((Some(c), len)) => {
+				push_chars(&mut out, c.to_uppercase());
+				&self.0[len..]
+			}
+			_ => &self.0,
+		};
+		out.extend_from_slice(rest);
+		RawString::from(out)
+	}
+
+	/// Returns a copy of the string with the first character of each word mapped to uppercase,
+	/// and the other characters of each word mapped to lowercase.
+	///
+	/// Words are runs of alphanumeric characters, which may contain apostrophes,
+	/// so `don't` becomes `Don't`. Everything else, including invalid bytes, separates words
+	/// and is left unchanged.
+	///
+	/// Characters are mapped with their uppercase mapping, as there is no titlecase mapping
+	/// in the standard library; the two only differ for a few digraphs such as `ǆ`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let name = RawStr::new(b"jEAN-luc o'neill\xFFdupont");
+	/// assert_eq!(name.to_titlecase().as_ref(), b"Jean-Luc O'neill\xFFDupont");
+	/// ```
+	#[must_use]
+	pub fn to_titlecase(&self) -> RawString {
+		let mut out = Vec::with_capacity(self.len());
+		let mut in_word = false;
+		let mut rest = &self.0[..];
+		while let Some((c, len)) = utf8::decode_first(rest) {
+			match c {
+				Some(c) if c.is_alphanumeric() => {
+					if in_word {
+						push_chars(&mut out, c.to_lowercase());
+					} else {
+						push_chars(&mut out, c.to_uppercase());
+					}
+					in_word = true;
+				}
+				Some('\'' | '\u{2019}') if in_word => out.extend_from_slice(&rest[..len]),
+				_ => {
+					out.extend_from_slice(&rest[..len]);
+					in_word = false;
+				}
+			}
+			rest = &rest[len..];
+		}
+		RawString::from(out)
+	}
+}
+
+#[cfg(feature = "caseless")]
+impl RawStr {
+	/// Returns the case folded equivalent of the string as a new [`RawString`].
+	///
+	/// Valid UTF-8 is mapped with the full Unicode default case folding,
+	/// which erases case distinctions (for example `ß` and `ẞ` both fold to `ss`).
+	/// Invalid bytes are copied unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"Stra\xE1\xBA\x9Ee\xFF");
+	/// assert_eq!(raw.to_case_folded().as_ref(), b"strasse\xFF");
+	/// ```
+	#[must_use]
+	pub fn to_case_folded(&self) -> RawString {
+		map_valid(self, caseless::default_case_fold_str)
+	}
+
+	/// Returns `true` if `self` and `other` are equal after full Unicode case folding.
+	///
+	/// This is equivalent to comparing the results of [`to_case_folded`](Self::to_case_folded),
+	/// without allocating. Invalid bytes only match the same bytes.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"GR\xC3\x9CSSE\xFF");
+	/// assert!(raw.eq_ignore_case(b"gr\xC3\xBC\xC3\x9Fe\xFF"));
+	/// assert!(!raw.eq_ignore_case(b"grusse\xFF"));
+	/// assert!(RawStr::new("ΣΊΣΥΦΟΣ").eq_ignore_case("σίσυφος"));
+	/// ```
+	#[must_use]
+	pub fn eq_ignore_case<B>(&self, other: &B) -> bool
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		case_folded_units(&self.0).eq(case_folded_units(other.as_ref()))
+	}
+}
+
+/// Returns the case folded characters of `bytes`, with invalid bytes as errors.
+#[cfg(feature = "caseless")]
+fn case_folded_units(bytes: &[u8]) -> impl Iterator<Item = Result<char, u8>> + '_ {
+	use caseless::Caseless;
+
+	let mut rest = bytes;
+	let units = std::iter::from_fn(move || {
+		let (c, len) = utf8::decode_first(rest)?;
+		let unit = &rest[..len];
+		rest = &rest[len..];
+		Some((c, unit))
+	});
+	units.flat_map(|(c, unit)| {
+		let invalid = if c.is_none() { unit } else { &[] };
+		c.into_iter().default_case_fold().map(Ok).chain(invalid.iter().map(|&b| Err(b)))
+	})
+}
+
+impl RawString {
+	/// Converts the string to ASCII uppercase in place, returning it.
+	///
+	/// See [`RawStr::to_ascii_uppercase`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let raw = RawString::from(b"host\xFF".as_slice()).into_ascii_uppercase();
+	/// assert_eq!(raw.as_ref(), b"HOST\xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn into_ascii_uppercase(mut self) -> RawString {
+		self.0.make_ascii_uppercase();
+		self
+	}
+
+	/// Converts the string to ASCII lowercase in place, returning it.
+	///
+	/// See [`RawStr::to_ascii_lowercase`].
+	#[inline]
+	#[must_use]
+	pub fn into_ascii_lowercase(mut self) -> RawString {
+		self.0.make_ascii_lowercase();
+		self
+	}
+}
+
+/// Maps each valid UTF-8 run of `s` with `f`, copying the invalid bytes in between.
+fn map_valid(s: &RawStr, f: impl Fn(&str) -> String) -> RawString {
+	let mut out = Vec::with_capacity(s.len());
+	for chunk in s.0.utf8_chunks() {
+		out.extend_from_slice(f(chunk.valid()).as_bytes());
+		out.extend_from_slice(chunk.invalid());
+	}
+	RawString::from(out)
+}
+
+/// Appends the UTF-8 encoding of `chars` to `out`.
+#[inline]
+fn push_chars(out: &mut Vec<u8>, chars: impl Iterator<Item = char>) {
+	for c in chars {
+		out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+	}
+}
@@ -0,0 +1,339 @@
+// rawstring::chars
+
+use crate::{RawStr, RawString, UNICODE_REPLACEMENT_CHARACTER, utf8};
+
+impl RawStr {
+	/// Returns an iterator over the characters of the string.
+	///
+	/// Valid UTF-8 sequences are decoded, and each maximal invalid subpart
+	/// (as defined by [`Utf8Chunks`](std::str::Utf8Chunks)) is replaced with a single
+	/// [`UNICODE_REPLACEMENT_CHARACTER`], exactly as with [`String::from_utf8_lossy`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// // a truncated four-byte sequence is a single invalid subpart,
+	/// // but two invalid bytes are two
+	/// let raw = RawStr::new(b"a\xF0\x9F\x92b\xFF\xFE\xC3\xA9");
+	/// let chars: String = raw.chars().collect();
+	/// assert_eq!(chars, "a\u{FFFD}b\u{FFFD}\u{FFFD}é");
+	///
+	/// // decoding from the back gives the same characters
+	/// let reversed: String = raw.chars().rev().collect();
+	/// assert_eq!(reversed, "é\u{FFFD}\u{FFFD}b\u{FFFD}a");
+	/// ```
+	#[inline]
+	pub fn chars(&self) -> Chars<'_> {
+		Chars { bytes: &self.0 }
+	}
+
+	/// Returns an iterator over the characters of the string and their byte offsets.
+	///
+	/// Characters are decoded as with [`chars`](Self::chars), so each replacement character
+	/// stands for an invalid subpart starting at the reported offset.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC3\xA9\xFF!");
+	/// let indices: Vec<(usize, char)> = raw.char_indices().collect();
+	/// assert_eq!(indices, [(0, 'é'), (2, '\u{FFFD}'), (3, '!')]);
+	/// assert_eq!(raw.char_indices().next_back(), Some((3, '!')));
+	/// ```
+	#[inline]
+	pub fn char_indices(&self) -> CharIndices<'_> {
+		CharIndices { offset: 0, chars: self.chars() }
+	}
+
+	/// Returns an iterator over the maximal runs of valid and invalid UTF-8 in the string.
+	///
+	/// Unlike [`<[u8]>::utf8_chunks`](slice::utf8_chunks), which splits invalid bytes into
+	/// their invalid subparts, consecutive invalid bytes are yielded as a single run,
+	/// so valid and invalid runs always alternate.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, Utf8Run};
+	/// let raw = RawStr::new(b"caf\xC3\xA9\xFF\xFE\xC3 ok");
+	/// let mut runs = raw.utf8_runs();
+	/// assert_eq!(runs.next(), Some(Utf8Run::Valid("café")));
+	/// assert_eq!(runs.next(), Some(Utf8Run::Invalid(b"\xFF\xFE\xC3")));
+	/// assert_eq!(runs.next(), Some(Utf8Run::Valid(" ok")));
+	/// assert_eq!(runs.next(), None);
+	/// ```
+	#[inline]
+	pub fn utf8_runs(&self) -> Utf8Runs<'_> {
+		Utf8Runs { bytes: &self.0 }
+	}
+}
+
+impl RawStr {
+	/// Returns `true` if `index` does not fall inside a valid UTF-8 sequence.
+	///
+	/// The start and end of the string are boundaries, and so are all the offsets
+	/// around invalid bytes, so splitting the string at a boundary never cuts a valid character.
+	/// Offsets past the end are not boundaries.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC3\xA9\xA9\xFF");
+	/// assert!(raw.is_char_boundary(0));
+	/// assert!(!raw.is_char_boundary(1));
+	/// assert!(raw.is_char_boundary(2));
+	/// // the second `\xA9` is a lone continuation byte
+	/// assert!(raw.is_char_boundary(3));
+	/// assert!(raw.is_char_boundary(4));
+	/// assert!(!raw.is_char_boundary(5));
+	/// ```
+	#[must_use]
+	pub fn is_char_boundary(&self, index: usize) -> bool {
+		if index == 0 || index == self.len() {
+			return true;
+		}
+		if index > self.len() || !utf8::is_continuation(self.0[index]) {
+			return index < self.len();
+		}
+		// units start at non-continuation bytes, so only the last one before `index` can span it
+		let min = index.saturating_sub(3);
+		let Some(start) = (min..index).rev().find(|&i| !utf8::is_continuation(self.0[i])) else {
+			return true;
+		};
+		match utf8::decode_first(&self.0[start..]) {
+			Some((Some(_), len)) => start + len <= index,
+			_ => true,
+		}
+	}
+
+	/// Returns the closest [char boundary](Self::is_char_boundary) at or before `index`.
+	///
+	/// Offsets past the end are clamped to the length of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\xE2\x82\xAC\xFF");
+	/// assert_eq!(raw.floor_char_boundary(3), 1);
+	/// assert_eq!(raw.floor_char_boundary(4), 4);
+	/// assert_eq!(raw.floor_char_boundary(9), 5);
+	/// ```
+	#[must_use]
+	pub fn floor_char_boundary(&self, index: usize) -> usize {
+		let mut index = index.min(self.len());
+		while !self.is_char_boundary(index) {
+			index -= 1;
+		}
+		index
+	}
+
+	/// Returns the closest [char boundary](Self::is_char_boundary) at or after `index`.
+	///
+	/// Offsets past the end are clamped to the length of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\xE2\x82\xAC\xFF");
+	/// assert_eq!(raw.ceil_char_boundary(2), 4);
+	/// assert_eq!(raw.ceil_char_boundary(5), 5);
+	/// ```
+	#[must_use]
+	pub fn ceil_char_boundary(&self, index: usize) -> usize {
+		let mut index = index.min(self.len());
+		while !self.is_char_boundary(index) {
+			index += 1;
+		}
+		index
+	}
+}
+
+impl RawString {
+	/// Shortens the string to at most `max_len` bytes, without cutting a valid UTF-8 sequence.
+	///
+	/// The string is truncated at the [floor char boundary](RawStr::floor_char_boundary)
+	/// of `max_len`, and left unchanged if it is not longer than `max_len`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\xFFpr\xC3\xA9view".as_slice());
+	/// raw.truncate_to_char_boundary(4);
+	/// assert_eq!(raw.as_ref(), b"\xFFpr");
+	/// ```
+	#[inline]
+	pub fn truncate_to_char_boundary(&mut self, max_len: usize) {
+		let len = RawStr::from_bytes(&self.0).floor_char_boundary(max_len);
+		self.0.truncate(len);
+	}
+}
+
+/// An iterator over the characters of a string, replacing invalid UTF-8.
+///
+/// Created by [`RawStr::chars`].
+#[derive(Clone, Debug)]
+pub struct Chars<'h> {
+	bytes: &'h [u8],
+}
+
+impl<'h> Chars<'h> {
+	/// Returns the part of the string that has not been iterated over yet.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut chars = RawStr::new(b"\xFFab").chars();
+	/// chars.next();
+	/// assert_eq!(chars.as_raw_str(), "ab");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &'h RawStr {
+		RawStr::from_bytes(self.bytes)
+	}
+}
+
+impl Iterator for Chars<'_> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (c, len) = utf8::decode_first(self.bytes)?;
+		self.bytes = &self.bytes[len..];
+		Some(c.unwrap_or(UNICODE_REPLACEMENT_CHARACTER))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// every unit is between one and four bytes long
+		(self.bytes.len().div_ceil(4), Some(self.bytes.len()))
+	}
+}
+
+impl DoubleEndedIterator for Chars<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (c, len) = utf8::decode_last(self.bytes)?;
+		self.bytes = &self.bytes[..self.bytes.len() - len];
+		Some(c.unwrap_or(UNICODE_REPLACEMENT_CHARACTER))
+	}
+}
+
+impl std::iter::FusedIterator for Chars<'_> {}
+
+/// An iterator over the characters of a string and their byte offsets, replacing invalid UTF-8.
+///
+/// Created by [`RawStr::char_indices`].
+#[derive(Clone, Debug)]
+pub struct CharIndices<'h> {
+	// the offset of the front of `chars` in the string
+	offset: usize,
+	chars: Chars<'h>,
+}
+
+impl<'h> CharIndices<'h> {
+	/// Returns the part of the string that has not been iterated over yet.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &'h RawStr {
+		self.chars.as_raw_str()
+	}
+
+	/// Returns the byte offset of the next character, or the length of the string if there is none.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut indices = RawStr::new(b"\xF0\x9F\x92\xA9x").char_indices();
+	/// indices.next();
+	/// assert_eq!(indices.offset(), 4);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+}
+
+impl Iterator for CharIndices<'_> {
+	type Item = (usize, char);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let len = self.chars.bytes.len();
+		let c = self.chars.next()?;
+		let offset = self.offset;
+		self.offset += len - self.chars.bytes.len();
+		Some((offset, c))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chars.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for CharIndices<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let c = self.chars.next_back()?;
+		Some((self.offset + self.chars.bytes.len(), c))
+	}
+}
+
+impl std::iter::FusedIterator for CharIndices<'_> {}
+
+/// A maximal run of valid or invalid UTF-8, yielded by [`Utf8Runs`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Utf8Run<'h> {
+	/// A run of valid UTF-8.
+	Valid(&'h str),
+	/// A run of bytes that are not valid UTF-8.
+	Invalid(&'h [u8]),
+}
+
+impl<'h> Utf8Run<'h> {
+	/// Returns the bytes of this chunk.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &'h RawStr {
+		match *self {
+			Self::Valid(s) => RawStr::new(s),
+			Self::Invalid(bytes) => RawStr::from_bytes(bytes),
+		}
+	}
+}
+
+/// An iterator over the maximal runs of valid and invalid UTF-8 in a string.
+///
+/// Created by [`RawStr::utf8_runs`].
+#[derive(Clone, Debug)]
+pub struct Utf8Runs<'h> {
+	bytes: &'h [u8],
+}
+
+impl<'h> Iterator for Utf8Runs<'h> {
+	type Item = Utf8Run<'h>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut chunks = self.bytes.utf8_chunks();
+		let first = chunks.next()?;
+		if !first.valid().is_empty() {
+			self.bytes = &self.bytes[first.valid().len()..];
+			return Some(Utf8Run::Valid(first.valid()));
+		}
+
+		// join the invalid subparts until the next valid character
+		let mut len = first.invalid().len();
+		for chunk in chunks {
+			if !chunk.valid().is_empty() {
+				break;
+			}
+			len += chunk.invalid().len();
+		}
+		let (invalid, rest) = self.bytes.split_at(len);
+		self.bytes = rest;
+		Some(Utf8Run::Invalid(invalid))
+	}
+}
+
+impl std::iter::FusedIterator for Utf8Runs<'_> {}
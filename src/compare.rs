@@ -0,0 +1,276 @@
+// rawstring::compare
+
+use std::cmp::Ordering;
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Returns the longest prefix shared by `self` and `other`,
+	/// as a slice of `self`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let a = RawStr::new(b"/srv/\xFFdata/a.log");
+	/// let b = RawStr::new(b"/srv/\xFFdata/b.log");
+	/// assert_eq!(a.common_prefix(b), b"/srv/\xFFdata/");
+	/// assert_eq!(a.common_prefix("x"), "");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn common_prefix<B>(&self, other: &B) -> &RawStr
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let len = common_prefix_len(&self.0, other.as_ref());
+		RawStr::from_bytes(&self.0[..len])
+	}
+
+	/// Returns the longest suffix shared by `self` and `other`,
+	/// as a slice of `self`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let a = RawStr::new(b"alpha\xFF.tar.gz");
+	/// assert_eq!(a.common_suffix("beta.tar.gz"), ".tar.gz");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn common_suffix<B>(&self, other: &B) -> &RawStr
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let len = common_suffix_len(&self.0, other.as_ref());
+		RawStr::from_bytes(&self.0[self.len() - len..])
+	}
+
+	/// Returns the longest prefix shared by all the given strings,
+	/// as a slice of the first one.
+	///
+	/// Returns an empty string if there are no strings.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let paths = ["/usr/lib/a", "/usr/lib64/b", "/usr/libexec"].map(RawStr::new);
+	/// assert_eq!(RawStr::common_prefix_of(paths), "/usr/lib");
+	/// assert_eq!(RawStr::common_prefix_of([]), "");
+	/// ```
+	#[must_use]
+	pub fn common_prefix_of<'a, I>(strings: I) -> &'a RawStr
+	where
+		I: IntoIterator<Item = &'a RawStr>
+	{
+		let mut strings = strings.into_iter();
+		let Some(mut prefix) = strings.next() else {
+			return RawStr::new("");
+		};
+		for s in strings {
+			if prefix.is_empty() {
+				break;
+			}
+			prefix = prefix.common_prefix(s);
+		}
+		prefix
+	}
+
+	/// Returns the longest suffix shared by all the given strings,
+	/// as a slice of the first one.
+	///
+	/// Returns an empty string if there are no strings.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let names = [&b"a\xFF.txt"[..], b"bb.txt", b"c.txt"].map(RawStr::new);
+	/// assert_eq!(RawStr::common_suffix_of(names), ".txt");
+	/// ```
+	#[must_use]
+	pub fn common_suffix_of<'a, I>(strings: I) -> &'a RawStr
+	where
+		I: IntoIterator<Item = &'a RawStr>
+	{
+		let mut strings = strings.into_iter();
+		let Some(mut suffix) = strings.next() else {
+			return RawStr::new("");
+		};
+		for s in strings {
+			if suffix.is_empty() {
+				break;
+			}
+			suffix = suffix.common_suffix(s);
+		}
+		suffix
+	}
+
+	/// Returns the longest contiguous byte sequence shared by `self` and `other`,
+	/// as a slice of `self`, together with its byte offset in `self`
+	/// and the byte offset of its first occurrence in `other`.
+	///
+	/// If several shared substrings have the maximum length,
+	/// the one that occurs first in `self` is returned.
+	/// If the inputs share no bytes at all, an empty slice at offsets `0` is returned.
+	///
+	/// This builds a suffix automaton of `other` and runs in time linear in the length of both inputs
+	/// for a fixed alphabet: each step scans the transitions of a state, of which there are at most 256.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let a = RawStr::new(b"\x00\x01HEADER\xFFbody");
+	/// let b = RawStr::new(b"junk HEADER\xFF more");
+	/// assert_eq!(a.longest_common_substring(b), (RawStr::new(b"HEADER\xFF"), 2, 5));
+	/// assert_eq!(a.longest_common_substring("zzz"), (RawStr::new(""), 0, 0));
+	/// ```
+	#[must_use]
+	pub fn longest_common_substring<B>(&self, other: &B) -> (&RawStr, usize, usize)
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let automaton = SuffixAutomaton::new(other.as_ref());
+
+		// (length, end in self, end in other) of the best match so far
+		let mut best = (0, 0, 0);
+		let mut state = 0;
+		let mut len = 0;
+		for (i, &b) in self.0.iter().enumerate() {
+			while state != 0 && automaton.next(state, b).is_none() {
+				state = automaton.states[state].link;
+				len = automaton.states[state].len;
+			}
+			match automaton.next(state, b) {
+				Some(next) => {
+					state = next;
+					len += 1;
+				}
+				None => {
+					state = 0;
+					len = 0;
+				}
+			}
+			if len > best.0 {
+				best = (len, i + 1, automaton.states[state].first_end + 1);
+			}
+		}
+
+		let (len, end, other_end) = best;
+		if len == 0 {
+			return (RawStr::new(""), 0, 0);
+		}
+		(RawStr::from_bytes(&self.0[end - len..end]), end - len, other_end - len)
+	}
+
+	/// Compares `self` and `other` bytewise as if both were converted to ASCII lowercase.
+	///
+	/// This orders strings consistently with [`eq_ignore_ascii_case`](slice::eq_ignore_ascii_case),
+	/// so it can be used to sort or binary search case-insensitively without allocating.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use std::cmp::Ordering;
+	///
+	/// assert_eq!(RawStr::new("Content-Type").cmp_ignore_ascii_case("content-type"), Ordering::Equal);
+	/// assert_eq!(RawStr::new("ACCEPT").cmp_ignore_ascii_case("accept-encoding"), Ordering::Less);
+	///
+	/// let mut headers = ["Host", "accept", "zone", "Date"].map(RawStr::new);
+	/// headers.sort_by(|a, b| a.cmp_ignore_ascii_case(b));
+	/// assert_eq!(headers, ["accept", "Date", "Host", "zone"]);
+	/// ```
+	#[must_use]
+	pub fn cmp_ignore_ascii_case<B>(&self, other: &B) -> Ordering
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let other = other.as_ref();
+		self.0.iter().map(u8::to_ascii_lowercase).cmp(other.iter().map(u8::to_ascii_lowercase))
+	}
+}
+
+/// A suffix automaton over bytes, recognizing every substring of its input.
+struct SuffixAutomaton {
+	states: Vec<AutomatonState>,
+}
+
+struct AutomatonState {
+	/// Length of the longest substring in this state.
+	len: usize,
+	/// Suffix link; the root links to itself.
+	link: usize,
+	/// Offset of the last byte of the first occurrence of this state's substrings.
+	first_end: usize,
+	/// Outgoing transitions, looked up linearly as most states only have a few.
+	next: Vec<(u8, usize)>,
+}
+
+impl SuffixAutomaton {
+	fn new(s: &[u8]) -> Self {
+		let mut states = Vec::with_capacity(2 * s.len() + 1);
+		states.push(AutomatonState { len: 0, link: 0, first_end: 0, next: Vec::new() });
+		let mut this = Self { states };
+
+		let mut last = 0;
+		for (i, &b) in s.iter().enumerate() {
+			let cur = this.states.len();
+			this.states.push(AutomatonState {
+				len: this.states[last].len + 1,
+				link: 0,
+				first_end: i,
+				next: Vec::new(),
+			});
+
+			let mut p = Some(last);
+			while let Some(state) = p.filter(|&state| this.next(state, b).is_none()) {
+				this.states[state].next.push((b, cur));
+				p = this.parent(state);
+			}
+
+			if let Some(state) = p {
+				let q = this.next(state, b).expect("transition exists");
+				if this.states[state].len + 1 == this.states[q].len {
+					this.states[cur].link = q;
+				} else {
+					// split `q` so that the automaton stays minimal
+					let clone = this.states.len();
+					this.states.push(AutomatonState {
+						len: this.states[state].len + 1,
+						link: this.states[q].link,
+						first_end: this.states[q].first_end,
+						next: this.states[q].next.clone(),
+					});
+					let mut p = Some(state);
+					while let Some(state) = p.filter(|&state| this.next(state, b) == Some(q)) {
+						let slot = this.states[state].next.iter_mut().find(|(c, _)| *c == b);
+						slot.expect("transition exists").1 = clone;
+						p = this.parent(state);
+					}
+					this.states[q].link = clone;
+					this.states[cur].link = clone;
+				}
+			}
+			last = cur;
+		}
+		this
+	}
+
+	#[inline]
+	fn next(&self, state: usize, b: u8) -> Option<usize> {
+		self.states[state].next.iter().find(|&&(c, _)| c == b).map(|&(_, next)| next)
+	}
+
+	#[inline]
+	fn parent(&self, state: usize) -> Option<usize> {
+		(state != 0).then_some(self.states[state].link)
+	}
+}
+
+#[inline]
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+	a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+#[inline]
+fn common_suffix_len(a: &[u8], b: &[u8]) -> usize {
+	a.iter().rev().zip(b.iter().rev()).take_while(|(x, y)| x == y).count()
+}
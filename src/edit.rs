@@ -0,0 +1,175 @@
+// rawstring::edit
+
+use crate::{RawStr, RawString, utf8};
+
+impl RawString {
+	/// Appends the UTF-8 encoding of a [`char`] to the end of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\xFF".to_vec());
+	/// raw.push_char('a');
+	/// raw.push_char('é');
+	/// assert_eq!(raw.as_ref(), b"\xFFa\xC3\xA9");
+	/// ```
+	#[inline]
+	pub fn push_char(&mut self, c: char) {
+		self.0.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+	}
+
+	/// Appends a string slice to the end of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from("key=");
+	/// raw.push_str("value");
+	/// assert_eq!(raw.as_ref(), "key=value");
+	/// ```
+	#[inline]
+	pub fn push_str(&mut self, s: &str) {
+		self.0.extend_from_slice(s.as_bytes());
+	}
+
+	/// Appends raw bytes to the end of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let mut raw = RawString::from("name: ");
+	/// raw.push_raw(RawStr::new(b"\xFFdata"));
+	/// raw.push_raw(b"\n");
+	/// assert_eq!(raw.as_ref(), b"name: \xFFdata\n");
+	/// ```
+	#[inline]
+	pub fn push_raw<B>(&mut self, bytes: &B)
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		self.0.extend_from_slice(bytes.as_ref());
+	}
+
+	/// Inserts raw bytes into the string at byte offset `idx`.
+	///
+	/// No character boundary is required: the bytes are inserted as they are.
+	///
+	/// # Panics
+	/// Panics if `idx` is greater than the length of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let mut raw = RawString::from("key=value");
+	/// raw.insert_raw(4, RawStr::new(b"\xFF"));
+	/// raw.insert_raw(0, b"# ");
+	/// assert_eq!(raw.as_ref(), b"# key=\xFFvalue");
+	/// ```
+	#[inline]
+	pub fn insert_raw<B>(&mut self, idx: usize, bytes: &B)
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		assert!(idx <= self.0.len(), "insertion index (is {idx}) should be <= len (is {})", self.0.len());
+		self.0.splice(idx..idx, bytes.as_ref().iter().copied());
+	}
+
+	/// Inserts a string slice into the string at byte offset `idx`.
+	///
+	/// # Panics
+	/// Panics if `idx` is greater than the length of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\xFF!".to_vec());
+	/// raw.insert_str(1, "abc");
+	/// assert_eq!(raw.as_ref(), b"\xFFabc!");
+	/// ```
+	#[inline]
+	pub fn insert_str(&mut self, idx: usize, s: &str) {
+		self.insert_raw(idx, s);
+	}
+
+	/// Inserts the UTF-8 encoding of a [`char`] into the string at byte offset `idx`.
+	///
+	/// # Panics
+	/// Panics if `idx` is greater than the length of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"a\xFFb".to_vec());
+	/// raw.insert_char(1, '→');
+	/// assert_eq!(raw.as_ref(), b"a\xE2\x86\x92\xFFb");
+	/// ```
+	#[inline]
+	pub fn insert_char(&mut self, idx: usize, c: char) {
+		self.insert_raw(idx, c.encode_utf8(&mut [0; 4]));
+	}
+
+	/// Splits the string in two at byte offset `at`,
+	/// returning the bytes from `at` onwards and keeping the bytes before it.
+	///
+	/// # Panics
+	/// Panics if `at` is greater than the length of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"header\xFF\r\nbody".to_vec());
+	/// let body = raw.split_off(9);
+	/// assert_eq!(raw.as_ref(), b"header\xFF\r\n");
+	/// assert_eq!(body.as_ref(), "body");
+	/// ```
+	#[inline]
+	pub fn split_off(&mut self, at: usize) -> RawString {
+		RawString(self.0.split_off(at))
+	}
+
+	/// Splits the string in two at byte offset `at` like [`split_off`](Self::split_off),
+	/// but only if `at` is a [char boundary](RawStr::is_char_boundary).
+	///
+	/// Returns [`None`], leaving the string unchanged, if `at` is out of bounds
+	/// or would cut a valid UTF-8 sequence.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from("néé");
+	/// assert_eq!(raw.split_off_checked(2), None);
+	/// assert_eq!(raw.split_off_checked(9), None);
+	/// assert_eq!(raw.split_off_checked(3).unwrap().as_ref(), "é");
+	/// assert_eq!(raw.as_ref(), "né");
+	/// ```
+	#[inline]
+	pub fn split_off_checked(&mut self, at: usize) -> Option<RawString> {
+		RawStr::from_bytes(&self.0).is_char_boundary(at).then(|| self.split_off(at))
+	}
+
+	/// Removes the last character from the string and returns it.
+	///
+	/// Returns `Ok` with the character if the string ends with a complete, valid UTF-8 character.
+	/// Otherwise only the last byte is removed and returned as `Err`.
+	/// Returns [`None`] if the string is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"a\xC3\xA9\xE2\x82".to_vec());
+	/// assert_eq!(raw.pop_char(), Some(Err(0x82)));
+	/// assert_eq!(raw.pop_char(), Some(Err(0xE2)));
+	/// assert_eq!(raw.pop_char(), Some(Ok('é')));
+	/// assert_eq!(raw.pop_char(), Some(Ok('a')));
+	/// assert_eq!(raw.pop_char(), None);
+	/// ```
+	pub fn pop_char(&mut self) -> Option<Result<char, u8>> {
+		match utf8::decode_last(&self.0)? {
+			(Some(c), len) => {
+				self.0.truncate(self.0.len() - len);
+				Some(Ok(c))
+			}
+			(None, _) => self.0.pop().map(Err),
+		}
+	}
+}
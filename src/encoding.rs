@@ -0,0 +1,67 @@
+// rawstring::encoding
+
+use std::borrow::Cow;
+
+use encoding_rs::Encoding;
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Decodes the string from the given legacy `encoding` into UTF-8.
+	///
+	/// Malformed sequences are replaced with the replacement character (�),
+	/// and the returned flag is `true` if any replacement was made.
+	/// The result borrows the string when it can be used as is,
+	/// such as ASCII input to an ASCII-compatible encoding.
+	///
+	/// No byte order mark is sniffed or removed: the string is decoded with `encoding` as it is.
+	/// See [`Encoding::decode_without_bom_handling`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use encoding_rs::{SHIFT_JIS, WINDOWS_1252};
+	///
+	/// let (text, malformed) = RawStr::new(b"caf\xE9 \x80").decode(WINDOWS_1252);
+	/// assert_eq!((&*text, malformed), ("café €", false));
+	///
+	/// let (text, malformed) = RawStr::new(b"\x93\xFA\x96\x7B\xFF").decode(SHIFT_JIS);
+	/// assert_eq!((&*text, malformed), ("日本\u{FFFD}", true));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn decode(&self, encoding: &'static Encoding) -> (Cow<'_, str>, bool) {
+		encoding.decode_without_bom_handling(&self.0)
+	}
+}
+
+impl RawString {
+	/// Encodes `s` into the given legacy `encoding`.
+	///
+	/// Characters that cannot be represented in `encoding` are replaced with
+	/// HTML decimal character references such as `&#9731;`,
+	/// and the returned flag is `true` if any replacement was made.
+	///
+	/// As specified by the WHATWG Encoding Standard, UTF-16 and `replacement` encode as UTF-8.
+	/// See [`Encoding::encode`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// use encoding_rs::{SHIFT_JIS, WINDOWS_1252};
+	///
+	/// let (raw, unmappable) = RawString::encode_from("café €", WINDOWS_1252);
+	/// assert_eq!(raw.as_ref(), b"caf\xE9 \x80");
+	/// assert!(!unmappable);
+	///
+	/// let (raw, unmappable) = RawString::encode_from("日本☃", SHIFT_JIS);
+	/// assert_eq!(raw.as_ref(), b"\x93\xFA\x96\x7B&#9731;");
+	/// assert!(unmappable);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn encode_from(s: &str, encoding: &'static Encoding) -> (RawString, bool) {
+		let (bytes, _, unmappable) = encoding.encode(s);
+		(RawString(bytes.into_owned()), unmappable)
+	}
+}
@@ -0,0 +1,326 @@
+// rawstring::error
+
+use std::{error::Error, fmt, num::ParseIntError, str::Utf8Error};
+
+use crate::RawStr;
+
+/// An error returned when converting a raw string to UTF-8 fails.
+///
+/// Besides the position of the error, this gives access to the offending bytes
+/// and to the original string, so that no data is lost on failure.
+/// `T` is the string that failed to convert: a [`&RawStr`](RawStr) for [`RawStr::to_str`],
+/// or a [`RawString`](crate::RawString) for [`RawString::to_utf8_checked`](crate::RawString::to_utf8_checked).
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawString};
+/// let err = RawStr::new(b"name=\xFF\xFEvalue").to_str().unwrap_err();
+/// assert_eq!(err.valid_up_to(), 5);
+/// assert_eq!(err.error_len(), Some(1));
+/// assert_eq!(err.invalid_bytes(), b"\xFF");
+/// assert_eq!(err.to_string(), "invalid utf-8 sequence `\\xff` from index 5");
+///
+/// let err = RawString::from(b"abc\xE2\x82".as_slice()).to_utf8_checked().unwrap_err();
+/// assert_eq!(err.error_len(), None);
+/// assert_eq!(err.into_inner().as_ref(), b"abc\xE2\x82");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawUtf8Error<T> {
+	pub(crate) bytes: T,
+	pub(crate) error: Utf8Error,
+}
+
+impl<T: AsRef<[u8]>> RawUtf8Error<T> {
+	/// Returns the length of the prefix of the string that is valid UTF-8.
+	///
+	/// See [`Utf8Error::valid_up_to`].
+	#[inline]
+	#[must_use]
+	pub fn valid_up_to(&self) -> usize {
+		self.error.valid_up_to()
+	}
+
+	/// Returns the length of the invalid sequence found at [`valid_up_to`](Self::valid_up_to),
+	/// or [`None`] if the string ends with an incomplete sequence.
+	///
+	/// See [`Utf8Error::error_len`].
+	#[inline]
+	#[must_use]
+	pub fn error_len(&self) -> Option<usize> {
+		self.error.error_len()
+	}
+
+	/// Returns the offending bytes: the invalid sequence,
+	/// or the incomplete sequence at the end of the string.
+	#[inline]
+	#[must_use]
+	pub fn invalid_bytes(&self) -> &RawStr {
+		let rest = &self.bytes.as_ref()[self.valid_up_to()..];
+		RawStr::from_bytes(&rest[..self.error_len().unwrap_or(rest.len())])
+	}
+
+	/// Returns the whole string that failed to convert.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &RawStr {
+		RawStr::from_bytes(self.bytes.as_ref())
+	}
+
+	/// Returns the underlying [`Utf8Error`].
+	#[inline]
+	#[must_use]
+	pub fn utf8_error(&self) -> Utf8Error {
+		self.error
+	}
+
+	/// Returns the string that failed to convert.
+	#[inline]
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		self.bytes
+	}
+}
+
+impl<T: AsRef<[u8]>> fmt::Display for RawUtf8Error<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let invalid = self.invalid_bytes().0.escape_ascii();
+		match self.error_len() {
+			Some(_) => write!(f, "invalid utf-8 sequence `{invalid}` from index {}", self.valid_up_to()),
+			None => write!(f, "incomplete utf-8 sequence `{invalid}` from index {}", self.valid_up_to()),
+		}
+	}
+}
+
+impl<T: AsRef<[u8]> + fmt::Debug> Error for RawUtf8Error<T> {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+/// An error returned when decoding a hexadecimal string fails.
+///
+/// Returned by [`RawString::from_hex`](crate::RawString::from_hex).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromHexError {
+	/// A byte that is not a hexadecimal digit was found.
+	InvalidDigit {
+		/// The byte offset of the invalid digit.
+		index: usize,
+		/// The invalid digit.
+		byte: u8,
+	},
+	/// The number of digits is odd.
+	OddLength,
+}
+
+impl fmt::Display for FromHexError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::InvalidDigit { index, byte } => {
+				write!(f, "invalid hexadecimal digit `{}` at index {index}", byte.escape_ascii())
+			}
+			Self::OddLength => f.write_str("odd number of hexadecimal digits"),
+		}
+	}
+}
+
+impl Error for FromHexError {}
+
+/// An error returned when decoding a Base32 string fails.
+///
+/// Returned by [`RawString::from_base32`](crate::RawString::from_base32).
+#[cfg(feature = "base32")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromBase32Error {
+	/// A byte that is not a symbol of the alphabet was found.
+	InvalidSymbol {
+		/// The byte offset of the invalid symbol.
+		index: usize,
+		/// The invalid symbol.
+		byte: u8,
+	},
+	/// The number of symbols or of padding bytes cannot result from encoding.
+	InvalidLength,
+}
+
+#[cfg(feature = "base32")]
+impl fmt::Display for FromBase32Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::InvalidSymbol { index, byte } => {
+				write!(f, "invalid base32 symbol `{}` at index {index}", byte.escape_ascii())
+			}
+			Self::InvalidLength => f.write_str("invalid base32 length"),
+		}
+	}
+}
+
+#[cfg(feature = "base32")]
+impl Error for FromBase32Error {}
+
+/// An error returned when parsing escape sequences fails.
+///
+/// Returned by [`RawStr::unescape`]. Each variant holds the byte offset
+/// of the backslash starting the malformed escape sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnescapeError {
+	/// A backslash is followed by a byte that starts no escape sequence.
+	UnknownEscape {
+		/// The byte offset of the escape sequence.
+		index: usize,
+	},
+	/// A `\x` escape is not followed by two hexadecimal digits.
+	InvalidHexEscape {
+		/// The byte offset of the escape sequence.
+		index: usize,
+	},
+	/// A `\u` escape is malformed or does not encode a Unicode scalar value.
+	InvalidUnicodeEscape {
+		/// The byte offset of the escape sequence.
+		index: usize,
+	},
+	/// The string ends with a backslash.
+	TrailingBackslash {
+		/// The byte offset of the backslash.
+		index: usize,
+	},
+}
+
+impl UnescapeError {
+	/// Returns the byte offset of the malformed escape sequence.
+	#[inline]
+	#[must_use]
+	pub fn index(&self) -> usize {
+		match *self {
+			Self::UnknownEscape { index }
+			| Self::InvalidHexEscape { index }
+			| Self::InvalidUnicodeEscape { index }
+			| Self::TrailingBackslash { index } => index,
+		}
+	}
+}
+
+impl fmt::Display for UnescapeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let what = match self {
+			Self::UnknownEscape { .. } => "unknown escape sequence",
+			Self::InvalidHexEscape { .. } => "invalid hexadecimal escape sequence",
+			Self::InvalidUnicodeEscape { .. } => "invalid unicode escape sequence",
+			Self::TrailingBackslash { .. } => "trailing backslash",
+		};
+		write!(f, "{what} at index {}", self.index())
+	}
+}
+
+impl Error for UnescapeError {}
+
+/// An error returned when splitting a command line into words fails.
+///
+/// Returned by [`RawStr::shell_split`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShellSplitError {
+	/// A single or double quote is never closed.
+	UnterminatedQuote {
+		/// The byte offset of the opening quote.
+		index: usize,
+	},
+	/// The string ends with an unquoted backslash.
+	TrailingBackslash {
+		/// The byte offset of the backslash.
+		index: usize,
+	},
+}
+
+impl fmt::Display for ShellSplitError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::UnterminatedQuote { index } => write!(f, "unterminated quote at index {index}"),
+			Self::TrailingBackslash { index } => write!(f, "trailing backslash at index {index}"),
+		}
+	}
+}
+
+impl Error for ShellSplitError {}
+
+/// An error returned when parsing a raw string into a value fails.
+///
+/// Returned by [`RawStr::parse`] for the types implementing [`FromStr`](std::str::FromStr),
+/// which only parse valid UTF-8. `E` is the error of their [`FromStr`](std::str::FromStr) implementation.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{ParseError, RawStr};
+/// let err = RawStr::new(b"4\xFF2").parse::<u8>().unwrap_err();
+/// assert!(matches!(err, ParseError::InvalidUtf8(e) if e.valid_up_to() == 1));
+///
+/// let err = RawStr::new("300").parse::<u8>().unwrap_err();
+/// assert_eq!(err.to_string(), "number too large to fit in target type");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError<E> {
+	/// The string is not valid UTF-8.
+	InvalidUtf8(Utf8Error),
+	/// The string is valid UTF-8, but could not be parsed.
+	Invalid(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::InvalidUtf8(e) => write!(f, "{e}"),
+			Self::Invalid(e) => write!(f, "{e}"),
+		}
+	}
+}
+
+impl<E: Error + 'static> Error for ParseError<E> {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidUtf8(e) => Some(e),
+			Self::Invalid(e) => Some(e),
+		}
+	}
+}
+
+/// An error returned when parsing an integer at the start of a raw string fails.
+///
+/// Returned by [`RawStr::parse_prefix`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::{ParsePrefixError, RawStr};
+/// let err = RawStr::new("x1").parse_prefix::<u8>().unwrap_err();
+/// assert_eq!(err, ParsePrefixError::NoDigits);
+/// assert_eq!(err.to_string(), "no digits at the start of the string");
+///
+/// let err = RawStr::new("256").parse_prefix::<u8>().unwrap_err();
+/// assert_eq!(err.to_string(), "number too large to fit in target type");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePrefixError {
+	/// The string does not start with a digit, after an optional sign.
+	NoDigits,
+	/// The integer does not fit in the target type.
+	OutOfRange(ParseIntError),
+}
+
+impl fmt::Display for ParsePrefixError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::NoDigits => write!(f, "no digits at the start of the string"),
+			Self::OutOfRange(e) => write!(f, "{e}"),
+		}
+	}
+}
+
+impl Error for ParsePrefixError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::NoDigits => None,
+			Self::OutOfRange(e) => Some(e),
+		}
+	}
+}
@@ -0,0 +1,351 @@
+// rawstring::escape
+
+use std::{ascii, char, fmt, iter::FusedIterator};
+
+use crate::{
+	RawStr, RawString, UnescapeError, bytes, utf8,
+	hex::{UPPER_HEX, hex_value},
+};
+
+impl RawStr {
+	/// Returns an iterator escaping each character of the string with [`char::escape_default`],
+	/// and each invalid byte as `\xNN`.
+	///
+	/// Only printable ASCII is left as is, so the output is always ASCII.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"tab\t\"\xC3\xA9\"\xFF");
+	/// assert_eq!(raw.escape_default().to_string(), r#"tab\t\"\u{e9}\"\xff"#);
+	/// ```
+	#[inline]
+	pub fn escape_default(&self) -> EscapeDefault<'_> {
+		EscapeDefault(Escape::new(&self.0, char::escape_default))
+	}
+
+	/// Returns an iterator escaping each character of the string with [`char::escape_debug`],
+	/// and each invalid byte as `\xNN`.
+	///
+	/// Printable Unicode is left as is.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"caf\xC3\xA9\n\x1B\xFF");
+	/// assert_eq!(raw.escape_debug().to_string(), r"café\n\u{1b}\xff");
+	///
+	/// // the escapes can be streamed into any writer
+	/// let mut out = String::new();
+	/// out.extend(raw.escape_debug().take(5));
+	/// assert_eq!(out, "café\\");
+	/// ```
+	#[inline]
+	pub fn escape_debug(&self) -> EscapeDebug<'_> {
+		EscapeDebug(Escape::new(&self.0, char::escape_debug))
+	}
+
+	/// Returns an iterator escaping each byte of the string with [`u8::escape_ascii`].
+	///
+	/// Every byte that is not printable ASCII is escaped, `\xNN` being used for all of them
+	/// except `\t`, `\r` and `\n`. Bytes are never decoded as UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC3\xA9\r\n\0'");
+	/// assert_eq!(raw.escape_bytes().to_string(), r"\xc3\xa9\r\n\x00\'");
+	/// ```
+	#[inline]
+	pub fn escape_bytes(&self) -> EscapeBytes<'_> {
+		EscapeBytes { bytes: self.0.iter(), current: None }
+	}
+}
+
+impl RawStr {
+	/// Renders the string as a C or C++ string literal, quotes included.
+	///
+	/// Printable ASCII is written as is except for `"`, `\` and `?`, which are escaped,
+	/// the latter so that no trigraph can form. `\n`, `\r`, `\t`, `\a`, `\b`, `\f` and `\v`
+	/// use their usual escapes, and every other byte a three-digit octal escape.
+	/// Octal escapes are used rather than hexadecimal ones because they end after three digits,
+	/// while a hexadecimal escape would swallow any hexadecimal digit following it.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFF1\0\"??=\n");
+	/// assert_eq!(raw.escape_c_literal(), r#""\3771\000\"\?\?=\n""#);
+	/// ```
+	#[must_use]
+	pub fn escape_c_literal(&self) -> String {
+		let mut out = String::with_capacity(self.len() + 2);
+		out.push('"');
+		for &b in &self.0 {
+			match b {
+				b'"' | b'\\' | b'?' => {
+					out.push('\\');
+					out.push(char::from(b));
+				}
+				b'\n' => out.push_str("\\n"),
+				b'\r' => out.push_str("\\r"),
+				b'\t' => out.push_str("\\t"),
+				0x07 => out.push_str("\\a"),
+				0x08 => out.push_str("\\b"),
+				0x0C => out.push_str("\\f"),
+				0x0B => out.push_str("\\v"),
+				b' '..=b'~' => out.push(char::from(b)),
+				_ => {
+					out.push('\\');
+					for shift in [6, 3, 0] {
+						out.push(char::from(b'0' + ((b >> shift) & 7)));
+					}
+				}
+			}
+		}
+		out.push('"');
+		out
+	}
+
+	/// Renders the string as a Rust byte string literal, `b` prefix and quotes included.
+	///
+	/// Printable ASCII is written as is except for `"` and `\`, which are escaped,
+	/// `\n`, `\r`, `\t` and `\0` use their usual escapes, and every other byte `\xNN`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFFa\0\"\\\n");
+	/// assert_eq!(raw.escape_rust_byte_literal(), r#"b"\xFFa\0\"\\\n""#);
+	/// ```
+	#[must_use]
+	pub fn escape_rust_byte_literal(&self) -> String {
+		let mut out = String::with_capacity(self.len() + 3);
+		out.push_str("b\"");
+		for &b in &self.0 {
+			match b {
+				b'"' | b'\\' => {
+					out.push('\\');
+					out.push(char::from(b));
+				}
+				b'\n' => out.push_str("\\n"),
+				b'\r' => out.push_str("\\r"),
+				b'\t' => out.push_str("\\t"),
+				b'\0' => out.push_str("\\0"),
+				b' '..=b'~' => out.push(char::from(b)),
+				_ => {
+					out.push_str("\\x");
+					out.push(char::from(UPPER_HEX[usize::from(b >> 4)]));
+					out.push(char::from(UPPER_HEX[usize::from(b & 0xF)]));
+				}
+			}
+		}
+		out.push('"');
+		out
+	}
+}
+
+impl RawStr {
+	/// Parses the Rust and C escape sequences of the string into the bytes they stand for.
+	///
+	/// The recognized escape sequences are:
+	/// - `\n`, `\r`, `\t`, `\0`, `\\`, `\'` and `\"`, as well as `\a`, `\b`, `\f` and `\v` from C,
+	/// - `\xNN`, with two hexadecimal digits, for any byte, including non-ASCII ones,
+	/// - `\u{N}`, with one to six hexadecimal digits, and `\uNNNN`, with exactly four,
+	///   for the UTF-8 encoding of a Unicode scalar value.
+	///
+	/// All other bytes, including invalid UTF-8, are copied as they are.
+	///
+	/// # Errors
+	/// Returns an [`UnescapeError`] giving the byte offset of the first malformed escape sequence.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, UnescapeError};
+	/// let raw = RawStr::new(r#"key\t\"\xFF\x00\u{e9}é\n"#);
+	/// assert_eq!(raw.unescape().unwrap().as_ref(), b"key\t\"\xFF\x00\xC3\xA9\xC3\xA9\n");
+	///
+	/// assert_eq!(RawStr::new(r"ab\q").unescape(), Err(UnescapeError::UnknownEscape { index: 2 }));
+	/// assert_eq!(RawStr::new(r"\x4").unescape(), Err(UnescapeError::InvalidHexEscape { index: 0 }));
+	/// assert_eq!(RawStr::new(r"\u{d800}").unescape(), Err(UnescapeError::InvalidUnicodeEscape { index: 0 }));
+	/// assert_eq!(RawStr::new("end\\").unescape(), Err(UnescapeError::TrailingBackslash { index: 3 }));
+	/// ```
+	pub fn unescape(&self) -> Result<RawString, UnescapeError> {
+		let mut out = Vec::with_capacity(self.len());
+		let mut rest = &self.0[..];
+		while let Some(i) = bytes::find_byte(rest, b'\\') {
+			out.extend_from_slice(&rest[..i]);
+			let index = self.len() - rest.len() + i;
+			let (unescaped, len) = unescape_one(&rest[i..], index)?;
+			match unescaped {
+				Unescaped::Byte(b) => out.push(b),
+				Unescaped::Char(c) => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+			}
+			rest = &rest[i + len..];
+		}
+		out.extend_from_slice(rest);
+		Ok(RawString(out))
+	}
+}
+
+enum Unescaped {
+	Byte(u8),
+	Char(char),
+}
+
+/// Parses the escape sequence at the start of `s`, found at byte offset `index`.
+/// Returns what it stands for and its length.
+fn unescape_one(s: &[u8], index: usize) -> Result<(Unescaped, usize), UnescapeError> {
+	let Some(&kind) = s.get(1) else {
+		return Err(UnescapeError::TrailingBackslash { index });
+	};
+	let byte = match kind {
+		b'n' => b'\n',
+		b'r' => b'\r',
+		b't' => b'\t',
+		b'0' => b'\0',
+		b'\\' | b'\'' | b'"' => kind,
+		b'a' => 0x07,
+		b'b' => 0x08,
+		b'f' => 0x0C,
+		b'v' => 0x0B,
+		b'x' => {
+			let (high, low) = s.get(2..4)
+				.and_then(|digits| Some((hex_value(digits[0])?, hex_value(digits[1])?)))
+				.ok_or(UnescapeError::InvalidHexEscape { index })?;
+			return Ok((Unescaped::Byte(high << 4 | low), 4));
+		}
+		b'u' => {
+			let invalid = UnescapeError::InvalidUnicodeEscape { index };
+			let (digits, len) = if s.get(2) == Some(&b'{') {
+				let close = s.iter().take(10).position(|&b| b == b'}').ok_or(invalid)?;
+				(&s[3..close], close + 1)
+			} else {
+				(s.get(2..6).ok_or(invalid)?, 6)
+			};
+			if !(1..=6).contains(&digits.len()) {
+				return Err(invalid);
+			}
+			let value = digits.iter().try_fold(0, |value, &d| Some(value << 4 | u32::from(hex_value(d)?)));
+			let c = value.and_then(char::from_u32).ok_or(invalid)?;
+			return Ok((Unescaped::Char(c), len));
+		}
+		_ => return Err(UnescapeError::UnknownEscape { index }),
+	};
+	Ok((Unescaped::Byte(byte), 2))
+}
+
+/// The engine of [`EscapeDefault`] and [`EscapeDebug`].
+#[derive(Clone, Debug)]
+struct Escape<'h, E> {
+	bytes: &'h [u8],
+	escape_char: fn(char) -> E,
+	current_char: Option<E>,
+	current_byte: Option<ascii::EscapeDefault>,
+}
+
+impl<'h, E: Iterator<Item = char>> Escape<'h, E> {
+	#[inline]
+	fn new(bytes: &'h [u8], escape_char: fn(char) -> E) -> Self {
+		Self { bytes, escape_char, current_char: None, current_byte: None }
+	}
+
+	fn next_char(&mut self) -> Option<char> {
+		loop {
+			if let Some(c) = self.current_char.as_mut().and_then(Iterator::next) {
+				return Some(c);
+			}
+			if let Some(b) = self.current_byte.as_mut().and_then(Iterator::next) {
+				return Some(char::from(b));
+			}
+			match utf8::decode_first(self.bytes)? {
+				(Some(c), len) => {
+					self.current_char = Some((self.escape_char)(c));
+					self.bytes = &self.bytes[len..];
+				}
+				(None, _) => {
+					// the bytes after the first one of an invalid subpart are invalid on their own
+					self.current_byte = Some(ascii::escape_default(self.bytes[0]));
+					self.bytes = &self.bytes[1..];
+				}
+			}
+		}
+	}
+}
+
+/// An iterator over the characters of a string escaped with [`char::escape_default`].
+///
+/// Created by [`RawStr::escape_default`].
+#[derive(Clone, Debug)]
+pub struct EscapeDefault<'h>(Escape<'h, char::EscapeDefault>);
+
+impl Iterator for EscapeDefault<'_> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next_char()
+	}
+}
+
+impl FusedIterator for EscapeDefault<'_> {}
+
+impl fmt::Display for EscapeDefault<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.clone().try_for_each(|c| fmt::Write::write_char(f, c))
+	}
+}
+
+/// An iterator over the characters of a string escaped with [`char::escape_debug`].
+///
+/// Created by [`RawStr::escape_debug`].
+#[derive(Clone, Debug)]
+pub struct EscapeDebug<'h>(Escape<'h, char::EscapeDebug>);
+
+impl Iterator for EscapeDebug<'_> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next_char()
+	}
+}
+
+impl FusedIterator for EscapeDebug<'_> {}
+
+impl fmt::Display for EscapeDebug<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.clone().try_for_each(|c| fmt::Write::write_char(f, c))
+	}
+}
+
+/// An iterator over the bytes of a string escaped with [`u8::escape_ascii`].
+///
+/// Created by [`RawStr::escape_bytes`].
+#[derive(Clone, Debug)]
+pub struct EscapeBytes<'h> {
+	bytes: std::slice::Iter<'h, u8>,
+	current: Option<ascii::EscapeDefault>,
+}
+
+impl Iterator for EscapeBytes<'_> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(b) = self.current.as_mut().and_then(Iterator::next) {
+				return Some(char::from(b));
+			}
+			self.current = Some(self.bytes.next()?.escape_ascii());
+		}
+	}
+}
+
+impl FusedIterator for EscapeBytes<'_> {}
+
+impl fmt::Display for EscapeBytes<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.clone().try_for_each(|c| fmt::Write::write_char(f, c))
+	}
+}
@@ -0,0 +1,78 @@
+// rawstring::fields
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns an iterator over the fields of the string separated by `delimiter`,
+	/// honoring quoting and escaping.
+	///
+	/// - Between two `quote` bytes, `delimiter` does not end the field,
+	///   and a doubled `quote` stands for a literal one.
+	///   The quotes themselves are removed, and a field may mix quoted and unquoted parts.
+	/// - An `escape` byte makes the following byte literal, and is removed.
+	///   An `escape` at the very end of the string is kept.
+	///
+	/// Passing [`None`] disables quoting or escaping. As with [`split`](Self::split),
+	/// an empty string has a single empty field, and an unterminated quote
+	/// runs to the end of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let row = RawStr::new(b"id,\"last, first\",\"say \"\"hi\"\"\",a\\,b,\xFF");
+	/// let fields: Vec<RawString> = row.split_fields(b',', Some(b'"'), Some(b'\\')).collect();
+	/// let fields: Vec<&RawStr> = fields.iter().map(|f| f.as_ref()).collect();
+	/// assert_eq!(fields, [&b"id"[..], b"last, first", b"say \"hi\"", b"a,b", b"\xFF"]);
+	///
+	/// let tsv = RawStr::new("a\t'b\tc'\t");
+	/// assert_eq!(tsv.split_fields(b'\t', Some(b'\''), None).count(), 3);
+	/// ```
+	#[inline]
+	pub fn split_fields(&self, delimiter: u8, quote: Option<u8>, escape: Option<u8>) -> Fields<'_> {
+		Fields { rest: Some(&self.0), delimiter, quote, escape }
+	}
+}
+
+/// An iterator over the unquoted and unescaped fields of a string.
+///
+/// Created by [`RawStr::split_fields`].
+#[derive(Clone, Debug)]
+pub struct Fields<'h> {
+	// `None` once the last field has been yielded
+	rest: Option<&'h [u8]>,
+	delimiter: u8,
+	quote: Option<u8>,
+	escape: Option<u8>,
+}
+
+impl Iterator for Fields<'_> {
+	type Item = RawString;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = self.rest?;
+		let mut field = Vec::new();
+		let mut quoted = false;
+		let mut i = 0;
+		while let Some(&b) = rest.get(i) {
+			i += 1;
+			if Some(b) == self.escape && i < rest.len() {
+				field.push(rest[i]);
+				i += 1;
+			} else if Some(b) == self.quote {
+				if quoted && rest.get(i) == Some(&b) {
+					field.push(b);
+					i += 1;
+				} else {
+					quoted = !quoted;
+				}
+			} else if b == self.delimiter && !quoted {
+				self.rest = Some(&rest[i..]);
+				return Some(RawString::from(field));
+			} else {
+				field.push(b);
+			}
+		}
+		self.rest = None;
+		Some(RawString::from(field))
+	}
+}
@@ -0,0 +1,284 @@
+// rawstring::finder
+
+use crate::RawStr;
+
+/// A precompiled substring searcher for finding the first occurrence of a needle.
+///
+/// Building a [`Finder`] preprocesses the needle once,
+/// so it can then be run against many haystacks without repeating that work.
+/// With the `memchr` feature enabled (the default), this wraps `memchr::memmem::Finder`.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{Finder, RawStr};
+/// let finder = Finder::new("\r\n");
+/// let records = [RawStr::new(b"a\xFF\r\nb"), RawStr::new("no delimiter")];
+/// assert_eq!(finder.find(records[0]), Some(2));
+/// assert_eq!(finder.find(records[1]), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Finder<'n> {
+	#[cfg(feature = "memchr")]
+	inner: memchr::memmem::Finder<'n>,
+	#[cfg(not(feature = "memchr"))]
+	needle: std::borrow::Cow<'n, [u8]>,
+}
+
+impl<'n> Finder<'n> {
+	/// Creates a new [`Finder`] for the given needle.
+	#[inline]
+	#[must_use]
+	pub fn new<B>(needle: &'n B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let needle = needle.as_ref();
+		Self {
+			#[cfg(feature = "memchr")]
+			inner: memchr::memmem::Finder::new(needle),
+			#[cfg(not(feature = "memchr"))]
+			needle: std::borrow::Cow::Borrowed(needle),
+		}
+	}
+
+	/// Returns the byte offset of the first occurrence of the needle in `haystack`.
+	#[inline]
+	#[must_use]
+	pub fn find(&self, haystack: &RawStr) -> Option<usize> {
+		#[cfg(feature = "memchr")]
+		{
+			self.inner.find(&haystack.0)
+		}
+		#[cfg(not(feature = "memchr"))]
+		{
+			crate::bytes::find(&haystack.0, &self.needle)
+		}
+	}
+
+	/// Returns the needle this [`Finder`] searches for.
+	#[inline]
+	#[must_use]
+	pub fn needle(&self) -> &RawStr {
+		#[cfg(feature = "memchr")]
+		{
+			RawStr::from_bytes(self.inner.needle())
+		}
+		#[cfg(not(feature = "memchr"))]
+		{
+			RawStr::from_bytes(&self.needle)
+		}
+	}
+
+	/// Converts this [`Finder`] into one that owns its needle.
+	#[inline]
+	#[must_use]
+	pub fn into_owned(self) -> Finder<'static> {
+		Finder {
+			#[cfg(feature = "memchr")]
+			inner: self.inner.into_owned(),
+			#[cfg(not(feature = "memchr"))]
+			needle: std::borrow::Cow::Owned(self.needle.into_owned()),
+		}
+	}
+}
+
+/// A precompiled substring searcher for finding the last occurrence of a needle.
+///
+/// This is the reverse counterpart of [`Finder`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::{FinderReverse, RawStr};
+/// let finder = FinderReverse::new("/");
+/// assert_eq!(finder.rfind(RawStr::new(b"/usr/lib/\xFF")), Some(8));
+/// assert_eq!(finder.rfind(RawStr::new("file")), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FinderReverse<'n> {
+	#[cfg(feature = "memchr")]
+	inner: memchr::memmem::FinderRev<'n>,
+	#[cfg(not(feature = "memchr"))]
+	needle: std::borrow::Cow<'n, [u8]>,
+}
+
+impl<'n> FinderReverse<'n> {
+	/// Creates a new [`FinderReverse`] for the given needle.
+	#[inline]
+	#[must_use]
+	pub fn new<B>(needle: &'n B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let needle = needle.as_ref();
+		Self {
+			#[cfg(feature = "memchr")]
+			inner: memchr::memmem::FinderRev::new(needle),
+			#[cfg(not(feature = "memchr"))]
+			needle: std::borrow::Cow::Borrowed(needle),
+		}
+	}
+
+	/// Returns the byte offset of the last occurrence of the needle in `haystack`.
+	#[inline]
+	#[must_use]
+	pub fn rfind(&self, haystack: &RawStr) -> Option<usize> {
+		#[cfg(feature = "memchr")]
+		{
+			self.inner.rfind(&haystack.0)
+		}
+		#[cfg(not(feature = "memchr"))]
+		{
+			crate::bytes::rfind(&haystack.0, &self.needle)
+		}
+	}
+
+	/// Returns the needle this [`FinderReverse`] searches for.
+	#[inline]
+	#[must_use]
+	pub fn needle(&self) -> &RawStr {
+		#[cfg(feature = "memchr")]
+		{
+			RawStr::from_bytes(self.inner.needle())
+		}
+		#[cfg(not(feature = "memchr"))]
+		{
+			RawStr::from_bytes(&self.needle)
+		}
+	}
+
+	/// Converts this [`FinderReverse`] into one that owns its needle.
+	#[inline]
+	#[must_use]
+	pub fn into_owned(self) -> FinderReverse<'static> {
+		FinderReverse {
+			#[cfg(feature = "memchr")]
+			inner: self.inner.into_owned(),
+			#[cfg(not(feature = "memchr"))]
+			needle: std::borrow::Cow::Owned(self.needle.into_owned()),
+		}
+	}
+}
+
+/// A substring searcher for input that arrives in chunks.
+///
+/// Bytes are fed with [`push`](Self::push), which reports the absolute offsets
+/// (counted from the first byte ever pushed) of the matches that became complete,
+/// including those spanning chunk boundaries. Matches do not overlap,
+/// exactly as if the whole stream had been searched at once.
+///
+/// Only the last `needle.len() - 1` bytes of the stream are kept between pushes.
+/// An empty needle never matches.
+///
+/// # Examples
+/// ```
+/// # use rawstring::StreamFinder;
+/// let mut finder = StreamFinder::new("\r\n\r\n");
+/// assert_eq!(finder.push(b"HTTP/1.1 200 OK\r\n"), []);
+/// assert_eq!(finder.push(b"Server: \xFF\r"), []);
+/// assert_eq!(finder.push(b"\n\r\nbody"), [26]);
+/// assert_eq!(finder.position(), 34);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamFinder<'n> {
+	finder: Finder<'n>,
+	// the tail of the stream a match could still start in
+	carry: Vec<u8>,
+	// the number of bytes pushed so far
+	position: usize,
+	// the end of the last match reported, before which no match may start
+	last_end: usize,
+}
+
+impl<'n> StreamFinder<'n> {
+	/// Creates a new [`StreamFinder`] for the given needle.
+	#[inline]
+	#[must_use]
+	pub fn new<B>(needle: &'n B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		Self {
+			finder: Finder::new(needle),
+			carry: Vec::new(),
+			position: 0,
+			last_end: 0,
+		}
+	}
+
+	/// Feeds the next chunk of the stream and returns the absolute offsets
+	/// of the matches completed by it, in increasing order.
+	pub fn push(&mut self, chunk: &[u8]) -> Vec<usize> {
+		let n = self.finder.needle().len();
+		let mut found = Vec::new();
+		if n == 0 {
+			self.position += chunk.len();
+			return found;
+		}
+
+		let chunk_start = self.position;
+		let carry_start = chunk_start - self.carry.len();
+
+		// matches that start in the carried tail and end in this chunk
+		if !self.carry.is_empty() {
+			// the carry is extended in place with the head of the chunk, then restored
+			let carry_len = self.carry.len();
+			self.carry.extend_from_slice(&chunk[..chunk.len().min(n - 1)]);
+			let window = &self.carry;
+			let mut pos = self.last_end.saturating_sub(carry_start);
+			while let Some(i) = window.get(pos..).and_then(|rest| self.finder.find(RawStr::from_bytes(rest))) {
+				let start = carry_start + pos + i;
+				if start >= chunk_start {
+					break;
+				}
+				found.push(start);
+				self.last_end = start + n;
+				pos += i + n;
+			}
+			self.carry.truncate(carry_len);
+		}
+
+		// matches entirely inside this chunk
+		let mut pos = self.last_end.saturating_sub(chunk_start);
+		while let Some(i) = chunk.get(pos..).and_then(|rest| self.finder.find(RawStr::from_bytes(rest))) {
+			found.push(chunk_start + pos + i);
+			self.last_end = chunk_start + pos + i + n;
+			pos += i + n;
+		}
+
+		// keep the last `n - 1` bytes of the stream
+		let keep = n - 1;
+		if chunk.len() >= keep {
+			self.carry.clear();
+			self.carry.extend_from_slice(&chunk[chunk.len() - keep..]);
+		} else {
+			self.carry.extend_from_slice(chunk);
+			let excess = self.carry.len().saturating_sub(keep);
+			self.carry.drain(..excess);
+		}
+		self.position += chunk.len();
+
+		found
+	}
+
+	/// Returns the number of bytes pushed so far.
+	#[inline]
+	#[must_use]
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the needle this [`StreamFinder`] searches for.
+	#[inline]
+	#[must_use]
+	pub fn needle(&self) -> &RawStr {
+		self.finder.needle()
+	}
+
+	/// Forgets all pushed bytes, starting over as a new stream.
+	#[inline]
+	pub fn reset(&mut self) {
+		self.carry.clear();
+		self.position = 0;
+		self.last_end = 0;
+	}
+}
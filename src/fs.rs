@@ -0,0 +1,52 @@
+// rawstring::fs
+
+//! Filesystem helpers reading and writing [`RawString`]s.
+//!
+//! These mirror [`std::fs::read_to_string`] and [`std::fs::write`],
+//! but keep the contents of the files as they are, whether or not they are valid UTF-8.
+//!
+//! # Examples
+//! ```
+//! use rawstring::{RawStr, fs};
+//!
+//! let path = std::env::temp_dir().join("rawstring-fs-example.txt");
+//! fs::write(&path, RawStr::new(b"caf\xE9\r\n"))?;
+//! let mut contents = fs::read(&path)?;
+//! contents.push_str("au lait");
+//! fs::write(&path, &contents)?;
+//! assert_eq!(fs::read(&path)?.as_ref(), b"caf\xE9\r\nau lait");
+//! # std::fs::remove_file(&path)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{io, path::Path};
+
+use crate::RawString;
+
+/// Reads the entire contents of a file into a [`RawString`].
+///
+/// Unlike [`std::fs::read_to_string`], the contents do not need to be valid UTF-8.
+///
+/// # Errors
+/// Returns an error if the file cannot be opened or read. See [`std::fs::read`].
+#[inline]
+pub fn read<P>(path: P) -> io::Result<RawString>
+where
+	P: AsRef<Path>
+{
+	std::fs::read(path).map(RawString)
+}
+
+/// Writes a raw string as the entire contents of a file,
+/// creating the file if it does not exist and truncating it if it does.
+///
+/// # Errors
+/// Returns an error if the file cannot be created or written. See [`std::fs::write`].
+#[inline]
+pub fn write<P, B>(path: P, contents: &B) -> io::Result<()>
+where
+	P: AsRef<Path>,
+	B: ?Sized + AsRef<[u8]>
+{
+	std::fs::write(path, contents.as_ref())
+}
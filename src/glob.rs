@@ -0,0 +1,124 @@
+// rawstring::glob
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Returns `true` if the whole string matches the glob `pattern`.
+	///
+	/// Matching is done on bytes, so neither the string nor the pattern
+	/// needs to be valid UTF-8. The supported syntax is:
+	/// - `*` matches any sequence of bytes, including an empty one
+	/// - `?` matches any single byte
+	/// - `[abc]` matches any byte in the class; ranges like `[a-z]` are supported,
+	///   and `[!abc]` or `[^abc]` negate the class
+	/// - `\` escapes the following byte, so `\*` matches a literal `*`
+	///
+	/// A `[` without a closing `]` matches a literal `[`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let name = RawStr::new(b"report-\xFF2024.tar.gz");
+	/// assert!(name.matches_glob("report-*.tar.gz"));
+	/// assert!(name.matches_glob(b"report-\xFF20[0-9][0-9].*"));
+	/// assert!(name.matches_glob("report-?2024*"));
+	/// assert!(!name.matches_glob("*.zip"));
+	/// assert!(RawStr::new("a*b").matches_glob(r"a\*b"));
+	/// ```
+	#[must_use]
+	pub fn matches_glob<B>(&self, pattern: &B) -> bool
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pattern = pattern.as_ref();
+		let text = &self.0;
+
+		let mut p = 0;
+		let mut t = 0;
+		// where to resume after the most recent `*`: (pattern index, text index)
+		let mut backtrack = None;
+
+		while t < text.len() {
+			if pattern.get(p) == Some(&b'*') {
+				p += 1;
+				backtrack = Some((p, t));
+				continue;
+			}
+
+			if let Some(len) = match_token(&pattern[p..], text[t]) {
+				p += len;
+				t += 1;
+				continue;
+			}
+
+			// let the last `*` absorb one more byte and try again
+			match backtrack {
+				Some((star_p, star_t)) => {
+					p = star_p;
+					t = star_t + 1;
+					backtrack = Some((star_p, star_t + 1));
+				}
+				None => return false,
+			}
+		}
+
+		pattern[p..].iter().all(|&b| b == b'*')
+	}
+}
+
+/// Matches the first token of `pattern` (which is not a `*`) against `byte`.
+/// Returns the length of the token if it matches.
+fn match_token(pattern: &[u8], byte: u8) -> Option<usize> {
+	match *pattern {
+		[] => None,
+		[b'?', ..] => Some(1),
+		[b'\\', escaped, ..] => (escaped == byte).then_some(2),
+		[b'[', ..] => match match_class(pattern, byte) {
+			Some((true, len)) => Some(len),
+			Some((false, _)) => None,
+			None => (byte == b'[').then_some(1),
+		},
+		[literal, ..] => (literal == byte).then_some(1),
+	}
+}
+
+/// Matches the character class at the start of `pattern` against `byte`.
+/// Returns whether the class matched and the length of the class,
+/// or [`None`] if the class is not terminated.
+fn match_class(pattern: &[u8], byte: u8) -> Option<(bool, usize)> {
+	let mut i = 1;
+	let negated = matches!(pattern.get(i), Some(b'!' | b'^'));
+	if negated {
+		i += 1;
+	}
+
+	let mut matched = false;
+	let mut first = true;
+	loop {
+		let mut lo = *pattern.get(i)?;
+		if lo == b']' && !first {
+			return Some((matched != negated, i + 1));
+		}
+		first = false;
+		if lo == b'\\' {
+			i += 1;
+			lo = *pattern.get(i)?;
+		}
+		i += 1;
+
+		let mut hi = lo;
+		if pattern.get(i) == Some(&b'-') && pattern.get(i + 1).is_some_and(|&b| b != b']') {
+			i += 1;
+			hi = pattern[i];
+			if hi == b'\\' {
+				i += 1;
+				hi = *pattern.get(i)?;
+			}
+			i += 1;
+		}
+
+		if (lo..=hi).contains(&byte) {
+			matched = true;
+		}
+	}
+}
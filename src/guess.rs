@@ -0,0 +1,97 @@
+// rawstring::guess
+
+use chardetng::{EncodingDetector, Iso2022JpDetection, Utf8Detection};
+use encoding_rs::{Encoding, UTF_8};
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Guesses the encoding of the string, for text whose encoding is not labeled.
+	///
+	/// A byte order mark decides the encoding with [`Confidence::Certain`].
+	/// Otherwise, valid UTF-8 (including pure ASCII) is guessed as UTF-8 with [`Confidence::High`].
+	/// Otherwise, the frequency analysis of [`chardetng`] picks a legacy encoding,
+	/// with [`Confidence::Medium`] if the string decodes without errors in it,
+	/// and [`Confidence::Low`] if it does not.
+	///
+	/// UTF-32 is not supported by [`encoding_rs`], so it is never guessed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Confidence, RawStr};
+	/// use encoding_rs::{SHIFT_JIS, UTF_16LE, UTF_8, WINDOWS_1252};
+	///
+	/// let guess = RawStr::new(b"\xFF\xFEh\0i\0").guess_encoding();
+	/// assert_eq!((guess.encoding(), guess.confidence()), (UTF_16LE, Confidence::Certain));
+	///
+	/// let guess = RawStr::new("déjà vu").guess_encoding();
+	/// assert_eq!((guess.encoding(), guess.confidence()), (UTF_8, Confidence::High));
+	///
+	/// let guess = RawStr::new(b"Le caf\xE9 est d\xE9j\xE0 pr\xEAt, tr\xE8s bien.").guess_encoding();
+	/// assert_eq!((guess.name(), guess.confidence()), ("windows-1252", Confidence::Medium));
+	///
+	/// let text = b"\x82\xb1\x82\xf1\x82\xc9\x82\xbf\x82\xcd\x81\x41\x90\xa2\x8a\x45\x81\x42";
+	/// assert_eq!(RawStr::new(text).guess_encoding().encoding(), SHIFT_JIS);
+	/// ```
+	#[must_use]
+	pub fn guess_encoding(&self) -> EncodingGuess {
+		if let Some((encoding, _)) = Encoding::for_bom(&self.0) {
+			return EncodingGuess { encoding, confidence: Confidence::Certain };
+		}
+		if self.is_utf8() {
+			return EncodingGuess { encoding: UTF_8, confidence: Confidence::High };
+		}
+
+		let mut detector = EncodingDetector::new(Iso2022JpDetection::Allow);
+		detector.feed(&self.0, true);
+		let encoding = detector.guess(None, Utf8Detection::Deny);
+		let (_, malformed) = encoding.decode_without_bom_handling(&self.0);
+		let confidence = if malformed { Confidence::Low } else { Confidence::Medium };
+		EncodingGuess { encoding, confidence }
+	}
+}
+
+/// The result of [`RawStr::guess_encoding`]: an encoding and how confident the guess is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncodingGuess {
+	encoding: &'static Encoding,
+	confidence: Confidence,
+}
+
+impl EncodingGuess {
+	/// Returns the guessed encoding.
+	#[inline]
+	#[must_use]
+	pub fn encoding(&self) -> &'static Encoding {
+		self.encoding
+	}
+
+	/// Returns the canonical label of the guessed encoding, such as `"Shift_JIS"`.
+	///
+	/// See [`Encoding::name`].
+	#[inline]
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.encoding.name()
+	}
+
+	/// Returns how confident the guess is.
+	#[inline]
+	#[must_use]
+	pub fn confidence(&self) -> Confidence {
+		self.confidence
+	}
+}
+
+/// How confident an [`EncodingGuess`] is, from the least to the most confident.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+	/// The guessed encoding is the most likely one, but the string does not decode cleanly with it.
+	Low,
+	/// The guessed encoding is the most likely one, and the string decodes cleanly with it.
+	Medium,
+	/// The string is valid UTF-8, which is very unlikely to happen by chance in other encodings.
+	High,
+	/// The encoding is given by a byte order mark.
+	Certain,
+}
@@ -0,0 +1,94 @@
+// rawstring::hex
+
+use crate::{FromHexError, RawStr, RawString};
+
+pub(crate) const LOWER_HEX: &[u8; 16] = b"0123456789abcdef";
+pub(crate) const UPPER_HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+impl RawStr {
+	/// Encodes the string as lowercase hexadecimal, two digits per byte.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\x00\xFFok").to_hex(), "00ff6f6b");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_hex(&self) -> String {
+		encode(&self.0, LOWER_HEX)
+	}
+
+	/// Encodes the string as uppercase hexadecimal, two digits per byte.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\x00\xFFok").to_hex_upper(), "00FF6F6B");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_hex_upper(&self) -> String {
+		encode(&self.0, UPPER_HEX)
+	}
+}
+
+impl RawString {
+	/// Decodes a hexadecimal string, two digits per byte.
+	///
+	/// Both lowercase and uppercase digits are accepted.
+	///
+	/// # Errors
+	/// Returns a [`FromHexError`] giving the position of the first byte that is not
+	/// a hexadecimal digit, or reporting that the number of digits is odd.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{FromHexError, RawString};
+	/// assert_eq!(RawString::from_hex("00fF6f6B").unwrap().as_ref(), b"\x00\xFFok");
+	/// assert_eq!(RawString::from_hex("00fg"), Err(FromHexError::InvalidDigit { index: 3, byte: b'g' }));
+	/// assert_eq!(RawString::from_hex("abc"), Err(FromHexError::OddLength));
+	/// ```
+	pub fn from_hex<B>(hex: &B) -> Result<Self, FromHexError>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let hex = hex.as_ref();
+		let digit = |index: usize| {
+			let byte = hex[index];
+			hex_value(byte).ok_or(FromHexError::InvalidDigit { index, byte })
+		};
+
+		let mut out = Vec::with_capacity(hex.len() / 2);
+		for i in (0..hex.len() / 2).map(|i| 2 * i) {
+			out.push(digit(i)? << 4 | digit(i + 1)?);
+		}
+		if hex.len() % 2 != 0 {
+			// report an invalid last digit before the odd length
+			digit(hex.len() - 1)?;
+			return Err(FromHexError::OddLength);
+		}
+		Ok(Self(out))
+	}
+}
+
+/// Encodes `bytes` as hexadecimal with the given digits.
+fn encode(bytes: &[u8], digits: &[u8; 16]) -> String {
+	let mut out = String::with_capacity(2 * bytes.len());
+	for &b in bytes {
+		out.push(char::from(digits[usize::from(b >> 4)]));
+		out.push(char::from(digits[usize::from(b & 0xF)]));
+	}
+	out
+}
+
+/// Returns the value of a hexadecimal digit, in either case.
+#[inline]
+pub(crate) const fn hex_value(digit: u8) -> Option<u8> {
+	match digit {
+		b'0'..=b'9' => Some(digit - b'0'),
+		b'a'..=b'f' => Some(digit - b'a' + 10),
+		b'A'..=b'F' => Some(digit - b'A' + 10),
+		_ => None,
+	}
+}
@@ -0,0 +1,51 @@
+// rawstring::idna_imp
+
+use idna::Errors;
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Converts a domain name to its ASCII form, as specified by UTS #46,
+	/// mapping it and encoding each non-ASCII label as Punycode with the `xn--` prefix.
+	///
+	/// Invalid UTF-8 is replaced with U+FFFD, which is not allowed in domain names,
+	/// so it makes the conversion fail. See [`idna::domain_to_ascii`].
+	///
+	/// # Errors
+	/// Returns [`Errors`] if the domain name is not valid.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("Bücher.Example").to_idna_ascii().unwrap(), "xn--bcher-kva.example");
+	/// assert!(RawStr::new(b"b\xFFcher.example").to_idna_ascii().is_err());
+	/// ```
+	#[inline]
+	pub fn to_idna_ascii(&self) -> Result<String, Errors> {
+		idna::domain_to_ascii(&String::from_utf8_lossy(&self.0))
+	}
+
+	/// Converts a domain name to its Unicode form, as specified by UTS #46,
+	/// mapping it and decoding each label with the `xn--` prefix from Punycode.
+	///
+	/// The conversion always produces a result, where the parts that could not be converted
+	/// are kept or replaced, together with whether errors occurred.
+	/// Invalid UTF-8 is replaced with U+FFFD, which is reported as an error.
+	/// See [`idna::domain_to_unicode`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let (domain, result) = RawStr::new("xn--bcher-kva.EXAMPLE").to_idna_unicode();
+	/// assert_eq!(domain, "bücher.example");
+	/// assert!(result.is_ok());
+	///
+	/// let (domain, result) = RawStr::new(b"\xFF.example").to_idna_unicode();
+	/// assert_eq!(domain, "\u{FFFD}.example");
+	/// assert!(result.is_err());
+	/// ```
+	#[inline]
+	pub fn to_idna_unicode(&self) -> (String, Result<(), Errors>) {
+		idna::domain_to_unicode(&String::from_utf8_lossy(&self.0))
+	}
+}
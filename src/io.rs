@@ -0,0 +1,224 @@
+// rawstring::io
+
+use std::io::{self, BufRead, IsTerminal, Read, StdinLock, Write};
+
+use crate::RawString;
+
+/// Extension methods to read into [`RawString`]s from any [`Read`]er.
+///
+/// This trait is implemented for all readers.
+pub trait RawReadExt: Read {
+	/// Reads all the bytes until the end of the reader and appends them to `buf`,
+	/// returning the number of bytes read.
+	///
+	/// Unlike [`Read::read_to_string`], the bytes do not need to be valid UTF-8.
+	/// See [`Read::read_to_end`].
+	///
+	/// # Errors
+	/// Returns any error returned by the reader, other than [`Interrupted`](io::ErrorKind::Interrupted).
+	/// The bytes read before the error are still appended to `buf`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawReadExt, RawString};
+	/// let mut reader: &[u8] = b"world\xFF";
+	/// let mut raw = RawString::from("hello ");
+	/// assert_eq!(reader.read_to_raw_string(&mut raw)?, 6);
+	/// assert_eq!(raw.as_ref(), b"hello world\xFF");
+	/// # Ok::<(), std::io::Error>(())
+	/// ```
+	#[inline]
+	fn read_to_raw_string(&mut self, buf: &mut RawString) -> io::Result<usize> {
+		self.read_to_end(&mut buf.0)
+	}
+
+	/// Reads up to `n` bytes into a new [`RawString`], stopping early at the end of the reader.
+	///
+	/// # Errors
+	/// Returns any error returned by the reader, other than [`Interrupted`](io::ErrorKind::Interrupted).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawReadExt;
+	/// let mut reader: &[u8] = b"\x89PNG\r\n\x1A\n\0\0\0\x0DIHDR";
+	/// assert_eq!(reader.take_raw(8)?.as_ref(), b"\x89PNG\r\n\x1A\n");
+	/// assert_eq!(reader.take_raw(64)?.as_ref(), b"\0\0\0\x0DIHDR");
+	/// assert!(reader.take_raw(64)?.is_empty());
+	/// # Ok::<(), std::io::Error>(())
+	/// ```
+	fn take_raw(&mut self, n: u64) -> io::Result<RawString> {
+		// avoid reserving a huge buffer for a large limit on a short reader
+		let capacity = usize::try_from(n).unwrap_or(usize::MAX).min(8 * 1024);
+		let mut buf = Vec::with_capacity(capacity);
+		Read::take(&mut *self, n).read_to_end(&mut buf)?;
+		Ok(RawString(buf))
+	}
+}
+
+impl<R: Read + ?Sized> RawReadExt for R {}
+
+/// Extension methods to read [`RawString`]s from any [`BufRead`]er.
+///
+/// This trait is implemented for all buffered readers.
+pub trait RawBufReadExt: BufRead {
+	/// Returns an iterator over the lines of the reader, as [`RawString`]s.
+	///
+	/// Unlike [`BufRead::lines`], the lines do not need to be valid UTF-8.
+	/// As with it, each line is yielded without its terminating `\n` or `\r\n`,
+	/// and the last line may have no terminator.
+	///
+	/// # Errors
+	/// The iterator yields any error returned by the reader, other than [`Interrupted`](io::ErrorKind::Interrupted).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawBufReadExt, RawString};
+	/// let reader: &[u8] = b"first\r\nsecond \xFF\n\nlast";
+	/// let lines = reader.raw_lines().collect::<std::io::Result<Vec<RawString>>>()?;
+	/// assert_eq!(lines, [&b"first"[..], b"second \xFF", b"", b"last"].map(RawString::from));
+	/// # Ok::<(), std::io::Error>(())
+	/// ```
+	#[inline]
+	fn raw_lines(self) -> RawLines<Self>
+	where
+		Self: Sized
+	{
+		RawLines { reader: self }
+	}
+}
+
+impl<B: BufRead + ?Sized> RawBufReadExt for B {}
+
+/// An iterator over the lines of a [`BufRead`]er, as [`RawString`]s.
+///
+/// Created by [`RawBufReadExt::raw_lines`].
+#[derive(Debug)]
+pub struct RawLines<B> {
+	reader: B,
+}
+
+impl<B: BufRead> Iterator for RawLines<B> {
+	type Item = io::Result<RawString>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut line = Vec::new();
+		match self.reader.read_until(b'\n', &mut line) {
+			Ok(0) => None,
+			Ok(_) => {
+				if line.ends_with(b"\n") {
+					line.pop();
+					if line.ends_with(b"\r") {
+						line.pop();
+					}
+				}
+				Some(Ok(RawString(line)))
+			}
+			Err(e) => Some(Err(e)),
+		}
+	}
+}
+
+/// Reads all of the standard input into a [`RawString`].
+///
+/// Unlike [`io::read_to_string`], the input does not need to be valid UTF-8.
+///
+/// # Errors
+/// Returns any error returned when reading the standard input.
+///
+/// # Examples
+/// ```no_run
+/// // a filter removing the ANSI escape sequences from its input
+/// let input = rawstring::read_stdin()?;
+/// rawstring::write_stdout(&input.as_ref().strip_ansi_escapes())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[inline]
+pub fn read_stdin() -> io::Result<RawString> {
+	let mut buf = RawString::new();
+	io::stdin().lock().read_to_raw_string(&mut buf)?;
+	Ok(buf)
+}
+
+/// Returns an iterator over the lines of the standard input, as [`RawString`]s.
+///
+/// The standard input stays locked while the iterator is alive. See [`RawBufReadExt::raw_lines`].
+///
+/// # Examples
+/// ```no_run
+/// // a filter numbering the lines of its input
+/// use std::io::Write;
+///
+/// let mut stdout = std::io::stdout().lock();
+/// for (i, line) in rawstring::stdin_lines().enumerate() {
+///     let mut line = line?;
+///     line.push(b'\n');
+///     write!(stdout, "{:>6}  ", i + 1)?;
+///     rawstring::write_stdout(&line)?;
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[inline]
+pub fn stdin_lines() -> RawLines<StdinLock<'static>> {
+	io::stdin().lock().raw_lines()
+}
+
+/// Writes a raw string to the standard output.
+///
+/// The bytes are written as they are, whether or not they are valid UTF-8,
+/// so that the output of a filter is byte-for-byte what it produced.
+///
+/// On Windows, the console only accepts valid UTF-8, and the standard library rejects anything else
+/// with an error. So when the standard output is a console, invalid sequences are replaced with
+/// the replacement character (�), as with [`RawString::to_utf8_lossy`]; for this to only affect
+/// invalid bytes, each call should write whole characters. When the standard output is
+/// redirected to a file or a pipe, the bytes are written unchanged on all platforms.
+///
+/// # Errors
+/// Returns any error returned when writing to the standard output.
+///
+/// # Examples
+/// ```
+/// rawstring::write_stdout(b"caf\xE9\n")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[inline]
+pub fn write_stdout<B>(bytes: &B) -> io::Result<()>
+where
+	B: ?Sized + AsRef<[u8]>
+{
+	write_std(io::stdout().lock(), bytes.as_ref())
+}
+
+/// Writes a raw string to the standard error.
+///
+/// See [`write_stdout`], whose handling of invalid UTF-8 on Windows consoles applies here too.
+///
+/// # Errors
+/// Returns any error returned when writing to the standard error.
+///
+/// # Examples
+/// ```
+/// let path = rawstring::RawStr::new(b"/srv/\xFF.log");
+/// rawstring::write_stderr(b"warning: cannot open ")?;
+/// rawstring::write_stderr(path)?;
+/// rawstring::write_stderr("\n")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[inline]
+pub fn write_stderr<B>(bytes: &B) -> io::Result<()>
+where
+	B: ?Sized + AsRef<[u8]>
+{
+	write_std(io::stderr().lock(), bytes.as_ref())
+}
+
+/// Writes `bytes` to a standard stream, lossily if it is a Windows console.
+fn write_std<W>(mut stream: W, bytes: &[u8]) -> io::Result<()>
+where
+	W: Write + IsTerminal
+{
+	if cfg!(windows) && stream.is_terminal() {
+		return stream.write_all(String::from_utf8_lossy(bytes).as_bytes());
+	}
+	stream.write_all(bytes)
+}
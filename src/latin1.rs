@@ -0,0 +1,89 @@
+// rawstring::latin1
+
+use crate::{RawStr, RawString, utf8};
+
+impl RawString {
+	/// Decodes Latin-1 (ISO-8859-1) bytes into a UTF-8 [`RawString`].
+	///
+	/// Each byte is taken as the Unicode scalar value of the same number,
+	/// so this never fails and [`to_latin1_checked`](RawStr::to_latin1_checked) reverses it exactly.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let raw = RawString::from_latin1(b"caf\xE9 \xA9 \xFF");
+	/// assert_eq!(raw.as_ref(), "café © ÿ");
+	/// assert_eq!(raw.as_ref().to_latin1_checked().unwrap().as_ref(), b"caf\xE9 \xA9 \xFF");
+	/// ```
+	#[must_use]
+	pub fn from_latin1<B>(bytes: &B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let bytes = bytes.as_ref();
+		let ascii = RawStr::from_bytes(bytes).ascii_prefix_len();
+		let mut out = Vec::with_capacity(bytes.len() + (bytes.len() - ascii));
+		out.extend_from_slice(&bytes[..ascii]);
+		for &b in &bytes[ascii..] {
+			if b.is_ascii() {
+				out.push(b);
+			} else {
+				out.extend_from_slice(&[0xC0 | (b >> 6), 0x80 | (b & 0x3F)]);
+			}
+		}
+		Self(out)
+	}
+}
+
+impl RawStr {
+	/// Encodes the string into Latin-1 (ISO-8859-1),
+	/// replacing each character above U+00FF and each invalid UTF-8 sequence with `?`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"na\xC3\xAFve \xE2\x82\xAC \xFF");
+	/// assert_eq!(raw.to_latin1_lossy().as_ref(), b"na\xEFve ? ?");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_latin1_lossy(&self) -> RawString {
+		RawString(encode_latin1(&self.0, Some(b'?')).expect("unmappable units are replaced"))
+	}
+
+	/// Encodes the string into Latin-1 (ISO-8859-1),
+	/// or returns [`None`] if it contains a character above U+00FF or invalid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("Müller").to_latin1_checked().unwrap().as_ref(), b"M\xFCller");
+	/// assert!(RawStr::new("€").to_latin1_checked().is_none());
+	/// assert!(RawStr::new(b"\xFF").to_latin1_checked().is_none());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_latin1_checked(&self) -> Option<RawString> {
+		encode_latin1(&self.0, None).map(RawString)
+	}
+}
+
+/// Encodes the UTF-8 in `bytes` into Latin-1.
+///
+/// Each unit that cannot be encoded is replaced with `replacement`,
+/// or makes the encoding fail if there is none.
+fn encode_latin1(bytes: &[u8], replacement: Option<u8>) -> Option<Vec<u8>> {
+	let mut out = Vec::with_capacity(bytes.len());
+	let mut rest = bytes;
+	loop {
+		let ascii = RawStr::from_bytes(rest).ascii_prefix_len();
+		out.extend_from_slice(&rest[..ascii]);
+		rest = &rest[ascii..];
+
+		let Some((c, len)) = utf8::decode_first(rest) else {
+			return Some(out);
+		};
+		out.push(c.and_then(|c| u8::try_from(c).ok()).or(replacement)?);
+		rest = &rest[len..];
+	}
+}
@@ -0,0 +1,436 @@
+// rawstring::layout
+
+use crate::{RawStr, RawString, lines, utf8};
+
+impl RawStr {
+	/// Pads the start of the string with repetitions of `pad`
+	/// until it is `width` bytes long, returning a new [`RawString`].
+	///
+	/// Only whole repetitions of `pad` are added, so the result may be shorter than `width`
+	/// if `pad` is longer than one byte. Strings already at least `width` bytes long are copied unchanged.
+	///
+	/// # Panics
+	/// Panics if `pad` is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xFF42").pad_start(6, b"0").as_ref(), b"000\xFF42");
+	/// assert_eq!(RawStr::new("toolong").pad_start(3, " ").as_ref(), "toolong");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn pad_start<B>(&self, width: usize, pad: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pad = pad.as_ref();
+		let count = pad_count(self.len(), width, pad.len());
+		padded(self, pad, count, 0)
+	}
+
+	/// Pads the end of the string with repetitions of `pad`
+	/// until it is `width` bytes long, returning a new [`RawString`].
+	///
+	/// See [`pad_start`](Self::pad_start).
+	///
+	/// # Panics
+	/// Panics if `pad` is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"id\xFF").pad_end(6, b".").as_ref(), b"id\xFF...");
+	/// assert_eq!(RawStr::new("ab").pad_end(7, "·").as_ref(), "ab··");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn pad_end<B>(&self, width: usize, pad: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pad = pad.as_ref();
+		let count = pad_count(self.len(), width, pad.len());
+		padded(self, pad, 0, count)
+	}
+
+	/// Pads both ends of the string with repetitions of `pad`
+	/// until it is `width` bytes long, returning a new [`RawString`].
+	///
+	/// When the padding cannot be split evenly, the extra repetition goes at the end,
+	/// as with centered [`format!`] arguments. See [`pad_start`](Self::pad_start).
+	///
+	/// # Panics
+	/// Panics if `pad` is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xFF").center(4, "*").as_ref(), b"*\xFF**");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn center<B>(&self, width: usize, pad: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pad = pad.as_ref();
+		let count = pad_count(self.len(), width, pad.len());
+		padded(self, pad, count / 2, count - count / 2)
+	}
+
+	/// Returns a copy of the string with `prefix` added at the start of each non-empty line.
+	///
+	/// Lines are split as with [`lines_with_terminator`](Self::lines_with_terminator),
+	/// so `\n` and `\r\n` endings are kept as they are. Lines with nothing before
+	/// their ending are not indented.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let blob = RawStr::new(b"error:\r\n\r\n\xFF at 0x10\n");
+	/// assert_eq!(blob.indent("  | ").as_ref(), b"  | error:\r\n\r\n  | \xFF at 0x10\n");
+	/// ```
+	#[must_use]
+	pub fn indent<B>(&self, prefix: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let prefix = prefix.as_ref();
+		let mut out = Vec::with_capacity(self.len());
+		for line in self.lines_with_terminator() {
+			if !is_blank_line(&line.0, |_| false) {
+				out.extend_from_slice(prefix);
+			}
+			out.extend_from_slice(&line.0);
+		}
+		RawString::from(out)
+	}
+
+	/// Returns a copy of the string with the leading whitespace common to all lines removed.
+	///
+	/// Only spaces and tabs are considered, and they must match exactly,
+	/// so a tab and a space are not a common prefix. Lines made only of whitespace
+	/// do not count towards the common prefix and are emptied, keeping their ending.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let block = RawStr::new(b"    fn main() {\r\n        \xFF\r\n  \r\n    }\n");
+	/// assert_eq!(block.dedent().as_ref(), b"fn main() {\r\n    \xFF\r\n\r\n}\n");
+	/// ```
+	#[must_use]
+	pub fn dedent(&self) -> RawString {
+		let is_indent = |b: u8| b == b' ' || b == b'\t';
+		let mut margin: Option<&[u8]> = None;
+		for line in self.lines() {
+			if is_blank_line(&line.0, is_indent) {
+				continue;
+			}
+			let indent = &line.0[..line.0.iter().position(|&b| !is_indent(b)).unwrap_or(line.len())];
+			margin = Some(match margin {
+				Some(margin) => &margin[..RawStr::from_bytes(margin).common_prefix(indent).len()],
+				None => indent,
+			});
+		}
+		let margin = margin.unwrap_or_default();
+
+		let mut out = Vec::with_capacity(self.len());
+		for line in self.lines_with_terminator() {
+			let line = &line.0;
+			if is_blank_line(line, is_indent) {
+				let content = line.iter().position(|&b| !is_indent(b)).unwrap_or(line.len());
+				out.extend_from_slice(&line[content..]);
+			} else {
+				out.extend_from_slice(&line[margin.len()..]);
+			}
+		}
+		RawString::from(out)
+	}
+
+	/// Returns a copy of the string with each tab replaced by the spaces
+	/// needed to reach the next multiple of `tab_width` columns.
+	///
+	/// Each character takes up one column, and so does each invalid byte.
+	/// Columns start over after `\n` and `\r`. A `tab_width` of zero removes tabs.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let row = RawStr::new(b"a\tb\xFF\tc\r\n\t\xC3\xA9\td");
+	/// assert_eq!(row.expand_tabs(4).as_ref(), b"a   b\xFF  c\r\n    \xC3\xA9   d");
+	/// ```
+	#[must_use]
+	pub fn expand_tabs(&self, tab_width: usize) -> RawString {
+		let mut out = Vec::with_capacity(self.len());
+		let mut column = 0;
+		let mut rest = &self.0[..];
+		while let Some((c, len)) = utf8::decode_first(rest) {
+			match c {
+				Some('\t') => {
+					let spaces = if tab_width == 0 { 0 } else { tab_width - column % tab_width };
+					out.resize(out.len() + spaces, b' ');
+					column += spaces;
+				}
+				Some('\n' | '\r') => {
+					out.extend_from_slice(&rest[..len]);
+					column = 0;
+				}
+				Some(_) => {
+					out.extend_from_slice(&rest[..len]);
+					column += 1;
+				}
+				None => {
+					out.extend_from_slice(&rest[..len]);
+					column += len;
+				}
+			}
+			rest = &rest[len..];
+		}
+		RawString::from(out)
+	}
+
+	/// Returns an iterator over the lines of the string wrapped to at most `width` bytes.
+	///
+	/// Lines are broken at whitespace, which is dropped at the break, and words
+	/// longer than `width` are broken where they hit the limit, between characters.
+	/// Existing `\n` and `\r\n` endings always start a new line and are not included.
+	/// Indentation is kept, unless the first word of the line does not fit after it,
+	/// and trailing whitespace is dropped.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let text = RawStr::new(b"the quick \xFFbrown fox\njumps");
+	/// let lines: Vec<&RawStr> = text.wrap(10).collect();
+	/// assert_eq!(lines, [&b"the quick"[..], b"\xFFbrown fox", b"jumps"]);
+	///
+	/// let lines: Vec<&RawStr> = RawStr::new("abcdefgh ij").wrap(3).collect();
+	/// assert_eq!(lines, ["abc", "def", "gh", "ij"]);
+	///
+	/// let lines: Vec<&RawStr> = RawStr::new("  ab cd\n    abcdef").wrap(5).collect();
+	/// assert_eq!(lines, ["  ab", "cd", "abcde", "f"]);
+	///
+	/// let lines: Vec<&RawStr> = RawStr::new("abc \n  ").wrap(3).collect();
+	/// assert_eq!(lines, ["abc"]);
+	///
+	/// let lines: Vec<&RawStr> = RawStr::new("a\u{E9}\u{E9}").wrap(2).collect();
+	/// assert_eq!(lines, ["a", "\u{E9}", "\u{E9}"]);
+	/// ```
+	#[inline]
+	pub fn wrap(&self, width: usize) -> Wrap<'_> {
+		Wrap { rest: &self.0, width, unit_width: |_, len| len }
+	}
+}
+
+#[cfg(feature = "unicode-width")]
+impl RawStr {
+	/// Returns an iterator over the lines of the string wrapped to at most `width` columns.
+	///
+	/// This is [`wrap`](Self::wrap), measuring characters with
+	/// [`UnicodeWidthChar::width`](unicode_width::UnicodeWidthChar::width)
+	/// and counting each invalid subpart as one column.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let lines: Vec<&RawStr> = RawStr::new("日本語 テキスト").wrap_columns(6).collect();
+	/// assert_eq!(lines, ["日本語", "テキス", "ト"]);
+	/// ```
+	#[inline]
+	pub fn wrap_columns(&self, width: usize) -> Wrap<'_> {
+		use unicode_width::UnicodeWidthChar;
+
+		Wrap { rest: &self.0, width, unit_width: |c, _| c.map_or(1, |c| c.width().unwrap_or(0)) }
+	}
+}
+
+/// An iterator over the wrapped lines of a string.
+///
+/// Created by [`RawStr::wrap`].
+#[derive(Clone, Debug)]
+pub struct Wrap<'h> {
+	rest: &'h [u8],
+	width: usize,
+	// the width of a unit given its character (if valid) and its length
+	unit_width: fn(Option<char>, usize) -> usize,
+}
+
+impl<'h> Iterator for Wrap<'h> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let is_space = |c: Option<char>| c.is_some_and(|c| c != '\n' && c.is_whitespace());
+		if skip_spaces(self.rest, is_space).is_empty() {
+			// only trailing whitespace is left
+			self.rest = &[];
+			return None;
+		}
+		let rest = self.rest;
+		// the length of the indentation, which is kept unless the first word does not fit after it
+		let indent = rest.len() - skip_spaces(rest, is_space).len();
+
+		let mut width = 0;
+		let mut i = 0;
+		// the start and end of the last run of whitespace after a word
+		let mut space: Option<(usize, usize)> = None;
+		while let Some((c, len)) = utf8::decode_first(&rest[i..]) {
+			if c == Some('\n') {
+				self.rest = &rest[i + 1..];
+				let line = lines::strip_line_ending(&rest[..=i]);
+				return Some(RawStr::from_bytes(trim_spaces_end(line, is_space)));
+			}
+			width += (self.unit_width)(c, len);
+			if is_space(c) {
+				match &mut space {
+					Some((_, end)) if *end == i => *end += len,
+					_ if i > indent => space = Some((i, i + len)),
+					_ => {}
+				}
+			} else if width > self.width {
+				let (line_end, next) = match space {
+					Some(space) => space,
+					// break at the indentation rather than inside the first word
+					None if indent > 0 => {
+						self.rest = &rest[indent..];
+						return self.next();
+					}
+					// always make progress, even if a single unit is too wide
+					None if i == 0 => (len, len),
+					None => (i, i),
+				};
+				self.rest = &rest[next..];
+				return Some(RawStr::from_bytes(&rest[..line_end]));
+			}
+			i += len;
+		}
+		self.rest = &[];
+		Some(RawStr::from_bytes(trim_spaces_end(rest, is_space)))
+	}
+}
+
+/// Returns `bytes` without its leading whitespace, stopping at line endings.
+fn skip_spaces(bytes: &[u8], is_space: impl Fn(Option<char>) -> bool) -> &[u8] {
+	let mut i = 0;
+	while let Some((c, len)) = utf8::decode_first(&bytes[i..]) {
+		if !is_space(c) {
+			break;
+		}
+		i += len;
+	}
+	&bytes[i..]
+}
+
+/// Returns `bytes` without its trailing whitespace.
+fn trim_spaces_end(bytes: &[u8], is_space: impl Fn(Option<char>) -> bool) -> &[u8] {
+	let mut end = bytes.len();
+	while let Some((c, len)) = utf8::decode_last(&bytes[..end]) {
+		if !is_space(c) {
+			break;
+		}
+		end -= len;
+	}
+	&bytes[..end]
+}
+
+/// Returns `true` if `line` only has bytes matching `is_blank` before its line ending.
+fn is_blank_line(line: &[u8], is_blank: impl Fn(u8) -> bool) -> bool {
+	lines::strip_line_ending(line).iter().all(|&b| is_blank(b))
+}
+
+#[cfg(feature = "unicode-width")]
+impl RawStr {
+	/// Pads the start of the string with repetitions of `pad`
+	/// until it takes up `width` columns when displayed, returning a new [`RawString`].
+	///
+	/// Both the string and `pad` are measured with [`display_width`](Self::display_width).
+	/// Only whole repetitions of `pad` are added, so the result may be narrower than `width`.
+	///
+	/// # Panics
+	/// Panics if `pad` takes up no columns.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("日本").pad_start_columns(6, " ").as_ref(), "  日本");
+	/// assert_eq!(RawStr::new("日本").pad_start(8, " ").as_ref(), "  日本");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn pad_start_columns<B>(&self, width: usize, pad: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pad = pad.as_ref();
+		let count = pad_count(self.display_width(), width, RawStr::from_bytes(pad).display_width());
+		padded(self, pad, count, 0)
+	}
+
+	/// Pads the end of the string with repetitions of `pad`
+	/// until it takes up `width` columns when displayed, returning a new [`RawString`].
+	///
+	/// See [`pad_start_columns`](Self::pad_start_columns).
+	///
+	/// # Panics
+	/// Panics if `pad` takes up no columns.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xFF\xC3\xA9").pad_end_columns(4, "-").as_ref(), b"\xFF\xC3\xA9--");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn pad_end_columns<B>(&self, width: usize, pad: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pad = pad.as_ref();
+		let count = pad_count(self.display_width(), width, RawStr::from_bytes(pad).display_width());
+		padded(self, pad, 0, count)
+	}
+
+	/// Pads both ends of the string with repetitions of `pad`
+	/// until it takes up `width` columns when displayed, returning a new [`RawString`].
+	///
+	/// See [`center`](Self::center) and [`pad_start_columns`](Self::pad_start_columns).
+	///
+	/// # Panics
+	/// Panics if `pad` takes up no columns.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("中").center_columns(5, " ").as_ref(), " 中  ");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn center_columns<B>(&self, width: usize, pad: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let pad = pad.as_ref();
+		let count = pad_count(self.display_width(), width, RawStr::from_bytes(pad).display_width());
+		padded(self, pad, count / 2, count - count / 2)
+	}
+}
+
+/// Returns how many pads of size `pad` fit between `len` and `width`.
+#[inline]
+fn pad_count(len: usize, width: usize, pad: usize) -> usize {
+	assert!(pad > 0, "padding must not be empty");
+	width.saturating_sub(len) / pad
+}
+
+/// Returns `s` with `before` repetitions of `pad` before it and `after` repetitions after it.
+fn padded(s: &RawStr, pad: &[u8], before: usize, after: usize) -> RawString {
+	let mut out = Vec::with_capacity(s.len() + (before + after) * pad.len());
+	for _ in 0..before {
+		out.extend_from_slice(pad);
+	}
+	out.extend_from_slice(&s.0);
+	for _ in 0..after {
+		out.extend_from_slice(pad);
+	}
+	RawString::from(out)
+}
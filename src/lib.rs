@@ -2,8 +2,68 @@
 #![doc = include_str!("../README.md")]
 #![feature(const_trait_impl, const_convert, const_cmp)]
 
+#[cfg(feature = "base32")]
+mod base32;
+#[cfg(feature = "base64")]
+mod base64_imp;
+mod binary;
+mod bom;
+mod bytes;
+mod case;
+mod chars;
+mod compare;
+mod edit;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod error;
+mod escape;
+mod fields;
+mod finder;
+mod glob;
+#[cfg(feature = "chardetng")]
+mod guess;
+mod hex;
+#[cfg(feature = "idna")]
+mod idna_imp;
+mod io;
+mod latin1;
+mod layout;
+mod lines;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+#[cfg(any(feature = "itoa", feature = "ryu"))]
+mod number;
+mod os;
+mod parse;
+mod percent;
+mod punycode;
+#[cfg(feature = "quoted-printable")]
+mod quoted_printable;
 mod raw_str_imp;
 mod raw_string_imp;
+#[cfg(feature = "regex")]
+mod regex_imp;
+mod replace;
+mod search;
+#[cfg(feature = "unicode-segmentation")]
+mod segment;
+#[cfg(feature = "aho-corasick")]
+mod set_matcher;
+mod shell;
+mod slice;
+mod split;
+mod terminal;
+mod transform;
+mod trim;
+mod utf16;
+mod utf8;
+#[cfg(feature = "unicode-width")]
+mod width;
+
+#[cfg(feature = "fs")]
+pub mod fs;
+pub mod pattern;
+pub mod similarity;
 
 #[doc(inline)]
 pub use raw_str_imp::RawStr;
@@ -11,6 +71,94 @@ pub use raw_str_imp::RawStr;
 #[doc(inline)]
 pub use raw_string_imp::RawString;
 
+#[doc(inline)]
+pub use binary::BinaryThresholds;
+
+#[doc(inline)]
+pub use bom::Bom;
+
+#[doc(inline)]
+pub use chars::{CharIndices, Chars, Utf8Run, Utf8Runs};
+
+#[doc(inline)]
+pub use error::{FromHexError, ParseError, ParsePrefixError, RawUtf8Error, ShellSplitError, UnescapeError};
+
+#[doc(inline)]
+pub use escape::{EscapeBytes, EscapeDebug, EscapeDefault};
+
+#[doc(inline)]
+pub use fields::Fields;
+
+#[doc(inline)]
+pub use finder::{Finder, FinderReverse, StreamFinder};
+
+#[doc(inline)]
+pub use io::{RawBufReadExt, RawLines, RawReadExt, read_stdin, stdin_lines, write_stderr, write_stdout};
+
+#[doc(inline)]
+pub use layout::Wrap;
+
+#[doc(inline)]
+pub use lines::{Lines, LinesWithTerminator, Paragraphs};
+
+#[doc(inline)]
+pub use parse::{FromRawStr, Integer};
+
+#[doc(inline)]
+pub use percent::PercentEncodeSet;
+
+#[doc(inline)]
+pub use replace::{DrainMatches, InvalidBytes, InvalidUtf8};
+
+#[doc(inline)]
+pub use search::{FindIter, Matches, MatchIndices, RMatches, RMatchIndices};
+
+#[doc(inline)]
+pub use slice::{Chunks, Windows};
+
+#[doc(inline)]
+pub use split::{
+	RSplit, RSplitN, RSplitTerminator, Split, SplitAsciiWhitespace, SplitInclusive, SplitN, SplitTerminator,
+	SplitWhitespace,
+};
+
+#[doc(inline)]
+pub use terminal::EscapeForTerminal;
+
+#[doc(inline)]
+pub use transform::LoneCr;
+
+#[doc(inline)]
+pub use utf16::EncodeUtf16;
+
+#[cfg(feature = "base32")]
+#[doc(inline)]
+pub use base32::Base32Alphabet;
+
+#[cfg(feature = "base32")]
+#[doc(inline)]
+pub use error::FromBase32Error;
+
+#[cfg(feature = "chardetng")]
+#[doc(inline)]
+pub use guess::{Confidence, EncodingGuess};
+
+#[cfg(any(feature = "itoa", feature = "ryu"))]
+#[doc(inline)]
+pub use number::{Number, write_num};
+
+#[cfg(feature = "unicode-segmentation")]
+#[doc(inline)]
+pub use segment::{GraphemeIndices, Graphemes, Sentences, WordIndices, Words};
+
+#[cfg(feature = "aho-corasick")]
+#[doc(inline)]
+pub use set_matcher::{RawStrSetMatcher, SetMatch, SetMatches};
+
+#[cfg(feature = "regex")]
+#[doc(inline)]
+pub use regex_imp::RawCaptures;
+
 /// The Unicode replacement character: `�`.
 /// 
 /// This character replaces invalid or unrepresentable characters
@@ -0,0 +1,143 @@
+// rawstring::lines
+
+use crate::{
+	RawStr,
+	pattern::ByteSearcher,
+	split::SplitInclusive,
+};
+
+impl RawStr {
+	/// Returns an iterator over the lines of the string.
+	///
+	/// Lines end with either `\n` or `\r\n`, which are not included in the lines.
+	/// A bare `\r` is not a line ending, and the last line may have no ending at all.
+	/// As with [`str::lines`], an ending at the end of the string does not produce an empty last line.
+	///
+	/// Lines may contain invalid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let text = RawStr::new(b"one\r\ntwo \xFF\n\nthree\r");
+	/// let lines: Vec<&RawStr> = text.lines().collect();
+	/// assert_eq!(lines, [&b"one"[..], b"two \xFF", b"", b"three\r"]);
+	/// ```
+	#[inline]
+	pub fn lines(&self) -> Lines<'_> {
+		Lines { split: self.split_inclusive(b'\n') }
+	}
+
+	/// Returns an iterator over the lines of the string, each with its original ending.
+	///
+	/// Lines are split as with [`lines`](Self::lines), but keep their `\n` or `\r\n`,
+	/// so concatenating them gives back the whole string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let text = RawStr::new(b"one\r\ntwo \xFF\nthree");
+	/// let lines: Vec<&RawStr> = text.lines_with_terminator().collect();
+	/// assert_eq!(lines, [&b"one\r\n"[..], b"two \xFF\n", b"three"]);
+	/// ```
+	#[inline]
+	pub fn lines_with_terminator(&self) -> LinesWithTerminator<'_> {
+		LinesWithTerminator { split: self.split_inclusive(b'\n') }
+	}
+
+	/// Returns an iterator over the paragraphs of the string.
+	///
+	/// Paragraphs are separated by one or more blank lines, that is
+	/// lines with nothing before their `\n` or `\r\n` ending.
+	/// Each paragraph spans from the start of its first line to the end of its last line,
+	/// without the ending of the last line. Leading and trailing blank lines are skipped.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let message = RawStr::new(b"From: a\r\nTo: b\r\n\r\nbody \xFF\r\nmore\n\n\n\nsignature\n");
+	/// let paragraphs: Vec<&RawStr> = message.paragraphs().collect();
+	/// assert_eq!(paragraphs, [&b"From: a\r\nTo: b"[..], b"body \xFF\r\nmore", b"signature"]);
+	/// ```
+	#[inline]
+	pub fn paragraphs(&self) -> Paragraphs<'_> {
+		Paragraphs { haystack: self, lines: self.lines_with_terminator(), offset: 0 }
+	}
+}
+
+/// An iterator over the lines of a string, without their endings.
+///
+/// Created by [`RawStr::lines`].
+#[derive(Clone, Debug)]
+pub struct Lines<'h> {
+	split: SplitInclusive<'h, ByteSearcher>,
+}
+
+impl<'h> Iterator for Lines<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let line = self.split.next()?;
+		Some(RawStr::from_bytes(strip_line_ending(&line.0)))
+	}
+}
+
+/// An iterator over the lines of a string, with their endings.
+///
+/// Created by [`RawStr::lines_with_terminator`].
+#[derive(Clone, Debug)]
+pub struct LinesWithTerminator<'h> {
+	split: SplitInclusive<'h, ByteSearcher>,
+}
+
+impl<'h> Iterator for LinesWithTerminator<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.split.next()
+	}
+}
+
+/// An iterator over the paragraphs of a string.
+///
+/// Created by [`RawStr::paragraphs`].
+#[derive(Clone, Debug)]
+pub struct Paragraphs<'h> {
+	haystack: &'h RawStr,
+	lines: LinesWithTerminator<'h>,
+	// the offset of the next line
+	offset: usize,
+}
+
+impl<'h> Iterator for Paragraphs<'h> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut start = None;
+		let mut end = 0;
+		for line in self.lines.by_ref() {
+			let line_start = self.offset;
+			self.offset += line.len();
+			let content = strip_line_ending(&line.0);
+			if content.is_empty() {
+				if start.is_some() {
+					break;
+				}
+				continue;
+			}
+			start.get_or_insert(line_start);
+			end = line_start + content.len();
+		}
+		start.map(|start| RawStr::from_bytes(&self.haystack.0[start..end]))
+	}
+}
+
+/// Removes the `\n` or `\r\n` ending of `line`, if any.
+#[inline]
+pub(crate) fn strip_line_ending(line: &[u8]) -> &[u8] {
+	match line.strip_suffix(b"\n") {
+		Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+		None => line,
+	}
+}
@@ -0,0 +1,92 @@
+// rawstring::normalize
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns a copy of the string in Normalization Form C (canonical composition).
+	///
+	/// Each valid UTF-8 run is normalized on its own, and invalid bytes are copied through unchanged,
+	/// so no data is lost. This is the form most systems, including Linux and the web, produce.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// // a file name from macOS, which decomposes accents, with a byte from a legacy encoding
+	/// let name = RawStr::new(b"cafe\xCC\x81-\xE9.txt");
+	/// assert_eq!(name.to_nfc().as_ref(), b"caf\xC3\xA9-\xE9.txt");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_nfc(&self) -> RawString {
+		self.normalize(|s, out| out.extend(s.nfc()))
+	}
+
+	/// Returns a copy of the string in Normalization Form D (canonical decomposition).
+	///
+	/// Invalid bytes are copied through unchanged, as with [`to_nfc`](Self::to_nfc).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let name = RawStr::new(b"caf\xC3\xA9\xFF");
+	/// assert_eq!(name.to_nfd().as_ref(), b"cafe\xCC\x81\xFF");
+	/// assert_eq!(name.to_nfd().as_ref().to_nfc().as_ref(), name);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_nfd(&self) -> RawString {
+		self.normalize(|s, out| out.extend(s.nfd()))
+	}
+
+	/// Returns a copy of the string in Normalization Form KC (compatibility composition).
+	///
+	/// Compatibility characters such as ligatures and full-width forms are replaced
+	/// by their plain equivalents, which is useful to compare identifiers or search text.
+	/// Invalid bytes are copied through unchanged, as with [`to_nfc`](Self::to_nfc).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xEF\xAC\x81le \xEF\xBC\xA1\xFF");
+	/// assert_eq!(raw.to_nfkc().as_ref(), b"file A\xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_nfkc(&self) -> RawString {
+		self.normalize(|s, out| out.extend(s.nfkc()))
+	}
+
+	/// Returns a copy of the string in Normalization Form KD (compatibility decomposition).
+	///
+	/// Invalid bytes are copied through unchanged, as with [`to_nfc`](Self::to_nfc).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC7\x84\xFE");
+	/// assert_eq!(raw.to_nfkd().as_ref(), b"DZ\xCC\x8C\xFE");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_nfkd(&self) -> RawString {
+		self.normalize(|s, out| out.extend(s.nfkd()))
+	}
+
+	/// Applies `f` to each valid UTF-8 run of the string, copying the invalid bytes in between.
+	fn normalize<F>(&self, f: F) -> RawString
+	where
+		F: Fn(&str, &mut String)
+	{
+		let mut out = Vec::with_capacity(self.len());
+		let mut buf = String::new();
+		for chunk in self.0.utf8_chunks() {
+			buf.clear();
+			f(chunk.valid(), &mut buf);
+			out.extend_from_slice(buf.as_bytes());
+			out.extend_from_slice(chunk.invalid());
+		}
+		RawString(out)
+	}
+}
@@ -0,0 +1,130 @@
+// rawstring::number
+
+use std::io;
+
+use crate::RawString;
+
+#[cfg(feature = "itoa")]
+impl RawString {
+	/// Appends the decimal representation of an integer to the string.
+	///
+	/// This formats the integer with [`itoa`], without going through [`format!`]
+	/// and a temporary [`String`], and writes the same digits as [`Display`](std::fmt::Display).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\xFFlen=".as_slice());
+	/// raw.push_int(1024_u32);
+	/// raw.push(b' ');
+	/// raw.push_int(i64::MIN);
+	/// assert_eq!(raw.as_ref(), b"\xFFlen=1024 -9223372036854775808");
+	/// ```
+	#[inline]
+	pub fn push_int<I: itoa::Integer>(&mut self, i: I) {
+		self.0.extend_from_slice(itoa::Buffer::new().format(i).as_bytes());
+	}
+}
+
+#[cfg(feature = "ryu")]
+impl RawString {
+	/// Appends the shortest decimal representation of a float that round-trips to the string.
+	///
+	/// This formats the float with [`ryu`], without going through [`format!`]
+	/// and a temporary [`String`]. The output can differ from [`Display`](std::fmt::Display):
+	/// very large and very small numbers use scientific notation, and finite numbers always
+	/// have a decimal point or an exponent. Non-finite values are written as `NaN`, `inf` and `-inf`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::new();
+	/// for f in [1.0, 0.1 + 0.2, 1e21, f64::NEG_INFINITY] {
+	///     raw.push_float(f);
+	///     raw.push(b',');
+	/// }
+	/// assert_eq!(raw.as_ref(), "1.0,0.30000000000000004,1e21,-inf,");
+	///
+	/// raw.clear();
+	/// raw.push_float(0.1_f32);
+	/// assert_eq!(raw.as_ref(), "0.1");
+	/// ```
+	#[inline]
+	pub fn push_float<F: ryu::Float>(&mut self, f: F) {
+		self.0.extend_from_slice(ryu::Buffer::new().format(f).as_bytes());
+	}
+}
+
+/// Writes a number to `writer`, formatted as with [`RawString::push_int`] or [`RawString::push_float`].
+///
+/// The number is formatted on the stack and written with a single call to
+/// [`write_all`](io::Write::write_all), so this is best used with a buffered writer.
+///
+/// # Errors
+/// Returns any error returned by `writer`.
+///
+/// # Examples
+/// ```
+/// # use rawstring::write_num;
+/// let mut out = Vec::new();
+/// write_num(&mut out, 42_u8)?;
+/// out.push(b'\t');
+/// write_num(&mut out, -2.5_f64)?;
+/// assert_eq!(out, b"42\t-2.5");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[inline]
+pub fn write_num<W, N>(mut writer: W, n: N) -> io::Result<()>
+where
+	W: io::Write,
+	N: Number
+{
+	n.with_bytes(|bytes| writer.write_all(bytes))
+}
+
+/// The primitive number types, which can be written with [`write_num`].
+///
+/// Integers require the `itoa` feature, and floats the `ryu` feature.
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Number: Copy + private::Sealed {}
+
+mod private {
+	pub trait Sealed {
+		/// Formats the number and passes its bytes to `f`.
+		fn with_bytes<R>(self, f: impl FnOnce(&[u8]) -> R) -> R;
+	}
+}
+
+#[cfg(feature = "itoa")]
+macro_rules! impl_number_itoa {
+	($($t:ty)*) => {$(
+		impl Number for $t {}
+
+		impl private::Sealed for $t {
+			#[inline]
+			fn with_bytes<R>(self, f: impl FnOnce(&[u8]) -> R) -> R {
+				f(itoa::Buffer::new().format(self).as_bytes())
+			}
+		}
+	)*};
+}
+
+#[cfg(feature = "itoa")]
+impl_number_itoa!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+#[cfg(feature = "ryu")]
+macro_rules! impl_number_ryu {
+	($($t:ty)*) => {$(
+		impl Number for $t {}
+
+		impl private::Sealed for $t {
+			#[inline]
+			fn with_bytes<R>(self, f: impl FnOnce(&[u8]) -> R) -> R {
+				f(ryu::Buffer::new().format(self).as_bytes())
+			}
+		}
+	)*};
+}
+
+#[cfg(feature = "ryu")]
+impl_number_ryu!(f32 f64);
@@ -0,0 +1,120 @@
+// rawstring::os
+
+use std::ffi::{OsStr, OsString};
+
+use crate::{RawStr, RawString};
+
+impl RawString {
+	/// Converts an [`OsString`] into a [`RawString`] without losing any data.
+	///
+	/// On Unix, the bytes are taken as they are.
+	/// On Windows, the wide string is decoded as [WTF-8](https://simonsapin.github.io/wtf-8/):
+	/// valid UTF-16 becomes UTF-8 and each unpaired surrogate is kept,
+	/// as with [`RawString::from_utf16`].
+	/// In both cases, [`RawStr::to_os_string`] gives back the original [`OsString`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// use std::ffi::OsString;
+	///
+	/// let name = OsString::from("report.txt");
+	/// let raw = RawString::from_os_string_lossless(name.clone());
+	/// assert_eq!(raw.as_ref(), "report.txt");
+	/// assert_eq!(raw.as_ref().to_os_string(), name);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn from_os_string_lossless(os: OsString) -> Self {
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStringExt;
+			Self(os.into_vec())
+		}
+		#[cfg(not(unix))]
+		{
+			Self::from_os_str_lossless(&os)
+		}
+	}
+
+	/// Converts an [`OsStr`] into a [`RawString`] without losing any data.
+	///
+	/// See [`from_os_string_lossless`](Self::from_os_string_lossless).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// use std::ffi::OsStr;
+	///
+	/// #[cfg(unix)]
+	/// {
+	///     use std::os::unix::ffi::OsStrExt;
+	///     let name = OsStr::from_bytes(b"caf\xE9.txt");
+	///     assert_eq!(RawString::from_os_str_lossless(name).as_ref(), b"caf\xE9.txt");
+	/// }
+	///
+	/// #[cfg(windows)]
+	/// {
+	///     use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+	///     let name = OsString::from_wide(&[0x61, 0xD800]);
+	///     let raw = RawString::from_os_str_lossless(&name);
+	///     assert_eq!(raw.as_ref(), b"a\xED\xA0\x80");
+	///     assert_eq!(raw.as_ref().to_os_string(), name);
+	/// }
+	/// ```
+	#[must_use]
+	pub fn from_os_str_lossless(os: &OsStr) -> Self {
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStrExt;
+			Self(os.as_bytes().to_vec())
+		}
+		#[cfg(windows)]
+		{
+			use std::os::windows::ffi::OsStrExt;
+			let units: Vec<u16> = os.encode_wide().collect();
+			Self::from_utf16(&units)
+		}
+		#[cfg(not(any(unix, windows)))]
+		{
+			Self(os.as_encoded_bytes().to_vec())
+		}
+	}
+}
+
+impl RawStr {
+	/// Converts the string into an [`OsString`].
+	///
+	/// This reverses [`RawString::from_os_string_lossless`].
+	/// On Unix, the bytes are taken as they are.
+	/// On Windows, the string is encoded as UTF-16 with [`encode_utf16`](Self::encode_utf16),
+	/// so surrogates encoded as WTF-8 are restored and invalid UTF-8 is replaced with U+FFFD.
+	/// On other platforms, invalid UTF-8 is replaced with U+FFFD.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use std::path::PathBuf;
+	///
+	/// let path = PathBuf::from(RawStr::new("/tmp/out.log").to_os_string());
+	/// assert_eq!(path.file_name().unwrap(), "out.log");
+	/// ```
+	#[must_use]
+	pub fn to_os_string(&self) -> OsString {
+		#[cfg(unix)]
+		{
+			use std::os::unix::ffi::OsStrExt;
+			OsStr::from_bytes(&self.0).to_os_string()
+		}
+		#[cfg(windows)]
+		{
+			use std::os::windows::ffi::OsStringExt;
+			let units: Vec<u16> = self.encode_utf16().collect();
+			OsString::from_wide(&units)
+		}
+		#[cfg(not(any(unix, windows)))]
+		{
+			OsString::from(String::from_utf8_lossy(&self.0).into_owned())
+		}
+	}
+}
@@ -0,0 +1,241 @@
+// rawstring::parse
+
+use std::{
+	num::{ParseFloatError, ParseIntError},
+	str::FromStr,
+};
+
+use crate::{ParseError, ParsePrefixError, RawStr};
+
+/// Parses a value from a [`RawStr`].
+///
+/// This is the [`RawStr`] counterpart of [`FromStr`], and is usually used through [`RawStr::parse`].
+///
+/// It is implemented for every type implementing [`FromStr`], including all the integer
+/// and floating point types, [`bool`], [`char`], [`IpAddr`](std::net::IpAddr) and
+/// [`SocketAddr`](std::net::SocketAddr): the string is checked to be valid UTF-8, then parsed
+/// with [`FromStr::from_str`]. Types that can be parsed from arbitrary bytes can implement it directly.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{FromRawStr, RawStr};
+/// struct Tag(Vec<u8>);
+///
+/// impl FromRawStr for Tag {
+///     type Err = ();
+///
+///     fn from_raw_str(s: &RawStr) -> Result<Self, ()> {
+///         let inner = s.strip_prefix("<").and_then(|s| s.strip_suffix(">")).ok_or(())?;
+///         Ok(Tag(inner.to_vec()))
+///     }
+/// }
+///
+/// let tag: Tag = RawStr::new(b"<\xFF>").parse().unwrap();
+/// assert_eq!(tag.0, b"\xFF");
+/// ```
+pub trait FromRawStr: Sized {
+	/// The error returned when parsing fails.
+	type Err;
+
+	/// Parses a value from `s`.
+	fn from_raw_str(s: &RawStr) -> Result<Self, Self::Err>;
+}
+
+impl<T: FromStr> FromRawStr for T {
+	type Err = ParseError<T::Err>;
+
+	#[inline]
+	fn from_raw_str(s: &RawStr) -> Result<Self, Self::Err> {
+		let s = std::str::from_utf8(&s.0).map_err(ParseError::InvalidUtf8)?;
+		s.parse().map_err(ParseError::Invalid)
+	}
+}
+
+impl RawStr {
+	/// Parses the string into another type.
+	///
+	/// See [`FromRawStr`] for the types that can be parsed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+	///
+	/// let line = RawStr::new(b"GET 200 0.25 10.0.0.1:8080 true \xFF");
+	/// let fields: Vec<&RawStr> = line.split(" ").collect();
+	/// assert_eq!(fields[1].parse::<u16>(), Ok(200));
+	/// assert_eq!(fields[2].parse::<f64>(), Ok(0.25));
+	/// assert_eq!(fields[3].parse::<SocketAddr>(), Ok(SocketAddr::from(([10, 0, 0, 1], 8080))));
+	/// assert_eq!(fields[4].parse::<bool>(), Ok(true));
+	/// assert!(fields[5].parse::<u32>().is_err());
+	///
+	/// let ip: IpAddr = RawStr::new("127.0.0.1").parse().unwrap();
+	/// assert_eq!(ip, Ipv4Addr::LOCALHOST);
+	/// ```
+	#[inline]
+	pub fn parse<T: FromRawStr>(&self) -> Result<T, T::Err> {
+		T::from_raw_str(self)
+	}
+}
+
+impl RawStr {
+	/// Parses the string as an integer in the given radix.
+	///
+	/// This is the [`RawStr`] counterpart of [`u32::from_str_radix`] and its siblings:
+	/// the string is an optional `+` or `-` sign followed by digits,
+	/// where letters stand for the digits above 9, whatever their case.
+	/// As with [`parse`](Self::parse), a string that is not valid UTF-8 fails with
+	/// [`ParseError::InvalidUtf8`], but ASCII strings are not validated.
+	///
+	/// # Panics
+	/// Panics if `radix` is not in the range from 2 to 36.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{ParseError, RawStr};
+	/// use std::num::IntErrorKind;
+	///
+	/// assert_eq!(RawStr::new("ff").parse_int_radix::<u8>(16), Ok(255));
+	/// assert_eq!(RawStr::new("-101").parse_int_radix::<i32>(2), Ok(-5));
+	/// assert_eq!(RawStr::new("zz").parse_int_radix::<u16>(36), Ok(1295));
+	///
+	/// let err = RawStr::new(b"7\xFF").parse_int_radix::<u32>(8).unwrap_err();
+	/// assert!(matches!(err, ParseError::InvalidUtf8(e) if e.valid_up_to() == 1));
+	/// let err = RawStr::new("12\u{E9}").parse_int_radix::<u32>(10).unwrap_err();
+	/// assert!(matches!(err, ParseError::Invalid(e) if e.kind() == &IntErrorKind::InvalidDigit));
+	/// ```
+	#[inline]
+	pub fn parse_int_radix<T: Integer>(&self, radix: u32) -> Result<T, ParseError<ParseIntError>> {
+		let s = if self.0.is_ascii() {
+			// SAFETY: ASCII is valid UTF-8
+			unsafe { std::str::from_utf8_unchecked(&self.0) }
+		} else {
+			std::str::from_utf8(&self.0).map_err(ParseError::InvalidUtf8)?
+		};
+		T::from_str_radix(s, radix).map_err(ParseError::Invalid)
+	}
+
+	/// Parses the longest decimal integer at the start of the string,
+	/// returning it along with the rest of the string.
+	///
+	/// The integer is an optional `+` or `-` sign followed by ASCII digits, as with [`parse`](Self::parse).
+	///
+	/// # Errors
+	/// Returns [`ParsePrefixError::NoDigits`] if the string does not start with an integer,
+	/// or [`ParsePrefixError::OutOfRange`] if the integer does not fit in `T`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{ParsePrefixError, RawStr};
+	/// use std::num::IntErrorKind;
+	///
+	/// // a length-prefixed field, as in many binary protocols
+	/// let (len, rest) = RawStr::new(b"5:\xFF\x00abc").parse_prefix::<usize>().unwrap();
+	/// assert_eq!((len, rest), (5, RawStr::new(b":\xFF\x00abc")));
+	///
+	/// assert_eq!(RawStr::new("-12px").parse_prefix::<i8>(), Ok((-12, RawStr::new("px"))));
+	/// assert_eq!(RawStr::new("42").parse_prefix::<u64>(), Ok((42, RawStr::new(""))));
+	///
+	/// assert_eq!(RawStr::new("px").parse_prefix::<u32>(), Err(ParsePrefixError::NoDigits));
+	/// assert_eq!(RawStr::new("-").parse_prefix::<i32>(), Err(ParsePrefixError::NoDigits));
+	/// let err = RawStr::new("300;").parse_prefix::<u8>().unwrap_err();
+	/// assert!(matches!(err, ParsePrefixError::OutOfRange(e) if e.kind() == &IntErrorKind::PosOverflow));
+	/// ```
+	pub fn parse_prefix<T: Integer>(&self) -> Result<(T, &RawStr), ParsePrefixError> {
+		let sign = usize::from(matches!(self.0.first(), Some(b'+' | b'-')));
+		let digits = self.0[sign..].iter().take_while(|b| b.is_ascii_digit()).count();
+		if digits == 0 {
+			return Err(ParsePrefixError::NoDigits);
+		}
+		let (number, rest) = self.0.split_at(sign + digits);
+		// SAFETY: the number is made of ASCII bytes only
+		let number = unsafe { std::str::from_utf8_unchecked(number) };
+		// a sign followed by digits can only fail to parse by overflowing
+		let value = T::from_str_radix(number, 10).map_err(ParsePrefixError::OutOfRange)?;
+		Ok((value, RawStr::from_bytes(rest)))
+	}
+}
+
+impl RawStr {
+	/// Parses the string as an `f32`.
+	///
+	/// See [`parse_f64`](Self::parse_f64).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("-1.5e3").parse_f32(), Ok(-1500.0));
+	/// assert!(RawStr::new(b"1.5\xFF").parse_f32().is_err());
+	/// ```
+	#[inline]
+	pub fn parse_f32(&self) -> Result<f32, ParseFloatError> {
+		parse_float(&self.0)
+	}
+
+	/// Parses the string as an `f64`.
+	///
+	/// This accepts the same syntax as [`f64::from_str`], including `inf` and `NaN`,
+	/// and is just as correctly rounded, as it is parsed by [`f64::from_str`] itself.
+	/// As a valid number is plain ASCII, the string is checked to be ASCII
+	/// instead of being validated as UTF-8, and input that is not ASCII is rejected
+	/// without being parsed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let row = RawStr::new(b"3.25,-0.5,1e-3,\xFF");
+	/// let values: Vec<_> = row.split(",").map(|field| field.parse_f64()).collect();
+	/// assert_eq!(values[..3], [Ok(3.25), Ok(-0.5), Ok(0.001)]);
+	/// assert!(values[3].is_err());
+	///
+	/// assert_eq!(RawStr::new("inf").parse_f64(), Ok(f64::INFINITY));
+	/// assert!(RawStr::new("").parse_f64().is_err());
+	/// ```
+	#[inline]
+	pub fn parse_f64(&self) -> Result<f64, ParseFloatError> {
+		parse_float(&self.0)
+	}
+}
+
+/// Parses a float from `bytes`, which can only be valid if they are ASCII.
+#[inline]
+fn parse_float<F>(bytes: &[u8]) -> Result<F, ParseFloatError>
+where
+	F: FromStr<Err = ParseFloatError>
+{
+	if bytes.is_ascii() {
+		// SAFETY: ASCII is valid UTF-8
+		unsafe { std::str::from_utf8_unchecked(bytes) }.parse()
+	} else {
+		// never a valid float, but this reports the appropriate error
+		String::from_utf8_lossy(bytes).parse()
+	}
+}
+
+/// The primitive integer types, which can be parsed with [`RawStr::parse_int_radix`] and [`RawStr::parse_prefix`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Integer: Copy + private::Sealed {}
+
+mod private {
+	use std::num::ParseIntError;
+
+	pub trait Sealed: Sized {
+		fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError>;
+	}
+}
+
+macro_rules! impl_integer {
+	($($t:ty)*) => {$(
+		impl Integer for $t {}
+
+		impl private::Sealed for $t {
+			#[inline]
+			fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+				<$t>::from_str_radix(s, radix)
+			}
+		}
+	)*};
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
@@ -0,0 +1,378 @@
+// rawstring::pattern
+
+//! Patterns accepted by the searching, splitting and trimming methods of [`RawStr`](crate::RawStr).
+//!
+//! A [`RawPattern`] can be any of:
+//! - a single byte: `b'a'`
+//! - a character, matched by its UTF-8 encoding: `'é'`
+//! - a byte string: `"abc"`, `b"abc"`, `&[0xFF, 0x00][..]`, a `&RawStr`, or anything else
+//!   that can be referenced as a byte slice
+//! - a set of characters, any of which match: `&['a', 'b']` or `&['a', 'b'][..]`
+//! - a byte predicate: `|b: u8| b.is_ascii_digit()`
+//! - a character predicate: `|c: char| c.is_whitespace()`
+//!
+//! Character sets and character predicates are only ever tested against
+//! valid UTF-8 sequences; invalid bytes never match them.
+//!
+//! Closure patterns need their argument type annotated,
+//! as it is what decides between a byte and a character predicate.
+//!
+//! # Examples
+//! ```
+//! # use rawstring::RawStr;
+//! let raw = RawStr::new(b"caf\xC3\xA9 \xFF 42");
+//! assert_eq!(raw.find(b' '), Some(5));
+//! assert_eq!(raw.find('é'), Some(3));
+//! assert_eq!(raw.find(b"\xFF"), Some(6));
+//! assert_eq!(raw.find(&['4', '2'][..]), Some(8));
+//! assert_eq!(raw.find(|b: u8| b.is_ascii_digit()), Some(8));
+//! assert_eq!(raw.find(|c: char| !c.is_ascii()), Some(3));
+//! ```
+
+use crate::{bytes, utf8};
+
+/// A pattern that can be searched for in a [`RawStr`](crate::RawStr).
+///
+/// See the [module documentation](self) for the list of implementors.
+///
+/// The `M` parameter is a marker that keeps the implementations for byte and
+/// character predicates apart; it is inferred and never needs to be named.
+pub trait RawPattern<M = ()>: Sized {
+	/// The searcher this pattern is turned into.
+	type Searcher: RawSearcher;
+
+	/// Turns this pattern into a searcher.
+	fn into_searcher(self) -> Self::Searcher;
+}
+
+/// Searches a haystack for the matches of a [`RawPattern`].
+///
+/// All matches are reported as `(start, end)` byte offsets into the given haystack.
+pub trait RawSearcher {
+	/// Returns the first match in `haystack`.
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)>;
+
+	/// Returns the last match in `haystack`.
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)>;
+
+	/// Returns the length of the match at the very start of `haystack`, if any.
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize>;
+
+	/// Returns the length of the match at the very end of `haystack`, if any.
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize>;
+
+	/// Returns the number of non-overlapping matches in `haystack`.
+	///
+	/// The default implementation repeatedly calls [`search`](Self::search);
+	/// searchers that can count faster than that should override it.
+	fn count_matches(&mut self, haystack: &[u8]) -> usize {
+		let mut count = 0;
+		let mut position = 0;
+		while let Some((start, end)) = haystack.get(position..).and_then(|rest| self.search(rest)) {
+			count += 1;
+			// step past empty matches so they are only counted once
+			position += if start == end { end + 1 } else { end };
+		}
+		count
+	}
+}
+
+/// Marker for [`RawPattern`] implementations on sets of characters.
+pub enum CharSetMarker {}
+
+/// Marker for [`RawPattern`] implementations on byte predicates.
+pub enum ByteFnMarker {}
+
+/// Marker for [`RawPattern`] implementations on character predicates.
+pub enum CharFnMarker {}
+
+/// Searcher for a single byte.
+#[derive(Clone, Debug)]
+pub struct ByteSearcher(u8);
+
+impl RawPattern for u8 {
+	type Searcher = ByteSearcher;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		ByteSearcher(self)
+	}
+}
+
+impl RawSearcher for ByteSearcher {
+	#[inline]
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		let i = bytes::find_byte(haystack, self.0)?;
+		Some((i, i + 1))
+	}
+
+	#[inline]
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		let i = bytes::rfind_byte(haystack, self.0)?;
+		Some((i, i + 1))
+	}
+
+	#[inline]
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+		(haystack.first() == Some(&self.0)).then_some(1)
+	}
+
+	#[inline]
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+		(haystack.last() == Some(&self.0)).then_some(1)
+	}
+
+	#[inline]
+	fn count_matches(&mut self, haystack: &[u8]) -> usize {
+		// simple enough for the compiler to vectorize
+		haystack.iter().filter(|&&b| b == self.0).count()
+	}
+}
+
+/// Searcher for a byte string.
+#[derive(Clone, Debug)]
+pub struct SliceSearcher<'a>(&'a [u8]);
+
+impl<'a, T: ?Sized + AsRef<[u8]>> RawPattern for &'a T {
+	type Searcher = SliceSearcher<'a>;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		SliceSearcher(self.as_ref())
+	}
+}
+
+impl RawSearcher for SliceSearcher<'_> {
+	#[inline]
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		let i = bytes::find(haystack, self.0)?;
+		Some((i, i + self.0.len()))
+	}
+
+	#[inline]
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		let i = bytes::rfind(haystack, self.0)?;
+		Some((i, i + self.0.len()))
+	}
+
+	#[inline]
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+		haystack.starts_with(self.0).then_some(self.0.len())
+	}
+
+	#[inline]
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+		haystack.ends_with(self.0).then_some(self.0.len())
+	}
+}
+
+/// Searcher for a single character.
+///
+/// The character is matched by its UTF-8 encoding. Since UTF-8 is self-synchronizing,
+/// a match always lines up with a complete sequence, even in invalid data.
+#[derive(Clone, Debug)]
+pub struct CharSearcher {
+	encoded: [u8; 4],
+	len: usize,
+}
+
+impl CharSearcher {
+	#[inline]
+	fn needle(&self) -> SliceSearcher<'_> {
+		SliceSearcher(&self.encoded[..self.len])
+	}
+}
+
+impl RawPattern for char {
+	type Searcher = CharSearcher;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		let mut encoded = [0; 4];
+		let len = self.encode_utf8(&mut encoded).len();
+		CharSearcher { encoded, len }
+	}
+}
+
+impl RawSearcher for CharSearcher {
+	#[inline]
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		self.needle().search(haystack)
+	}
+
+	#[inline]
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		self.needle().search_back(haystack)
+	}
+
+	#[inline]
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+		self.needle().match_prefix(haystack)
+	}
+
+	#[inline]
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+		self.needle().match_suffix(haystack)
+	}
+}
+
+/// Searcher for the characters accepted by a predicate.
+///
+/// Invalid UTF-8 sequences are skipped and never passed to the predicate.
+#[derive(Clone, Debug)]
+pub struct CharPredicateSearcher<F>(F);
+
+impl<F: FnMut(char) -> bool> RawPattern<CharFnMarker> for F {
+	type Searcher = CharPredicateSearcher<F>;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		CharPredicateSearcher(self)
+	}
+}
+
+impl<F: FnMut(char) -> bool> RawSearcher for CharPredicateSearcher<F> {
+	#[inline]
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		search_chars(haystack, &mut self.0)
+	}
+
+	#[inline]
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		search_chars_back(haystack, &mut self.0)
+	}
+
+	#[inline]
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+		match_char_prefix(haystack, &mut self.0)
+	}
+
+	#[inline]
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+		match_char_suffix(haystack, &mut self.0)
+	}
+}
+
+/// Searcher for a set of characters.
+///
+/// Invalid UTF-8 sequences never match.
+#[derive(Clone, Debug)]
+pub struct CharSetSearcher<'a>(&'a [char]);
+
+impl<'a> RawPattern<CharSetMarker> for &'a [char] {
+	type Searcher = CharSetSearcher<'a>;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		CharSetSearcher(self)
+	}
+}
+
+impl<'a, const N: usize> RawPattern<CharSetMarker> for &'a [char; N] {
+	type Searcher = CharSetSearcher<'a>;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		CharSetSearcher(self)
+	}
+}
+
+impl RawSearcher for CharSetSearcher<'_> {
+	#[inline]
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		search_chars(haystack, |c| self.0.contains(&c))
+	}
+
+	#[inline]
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		search_chars_back(haystack, |c| self.0.contains(&c))
+	}
+
+	#[inline]
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+		match_char_prefix(haystack, |c| self.0.contains(&c))
+	}
+
+	#[inline]
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+		match_char_suffix(haystack, |c| self.0.contains(&c))
+	}
+}
+
+fn search_chars(haystack: &[u8], mut f: impl FnMut(char) -> bool) -> Option<(usize, usize)> {
+	let mut pos = 0;
+	while let Some((c, len)) = utf8::decode_first(&haystack[pos..]) {
+		if c.is_some_and(&mut f) {
+			return Some((pos, pos + len));
+		}
+		pos += len;
+	}
+	None
+}
+
+fn search_chars_back(haystack: &[u8], mut f: impl FnMut(char) -> bool) -> Option<(usize, usize)> {
+	let mut end = haystack.len();
+	while let Some((c, len)) = utf8::decode_last(&haystack[..end]) {
+		if c.is_some_and(&mut f) {
+			return Some((end - len, end));
+		}
+		end -= len;
+	}
+	None
+}
+
+fn match_char_prefix(haystack: &[u8], mut f: impl FnMut(char) -> bool) -> Option<usize> {
+	match utf8::decode_first(haystack)? {
+		(Some(c), len) if f(c) => Some(len),
+		_ => None,
+	}
+}
+
+fn match_char_suffix(haystack: &[u8], mut f: impl FnMut(char) -> bool) -> Option<usize> {
+	match utf8::decode_last(haystack)? {
+		(Some(c), len) if f(c) => Some(len),
+		_ => None,
+	}
+}
+
+/// Searcher for the bytes accepted by a predicate.
+#[derive(Clone, Debug)]
+pub struct BytePredicateSearcher<F>(F);
+
+impl<F: FnMut(u8) -> bool> RawPattern<ByteFnMarker> for F {
+	type Searcher = BytePredicateSearcher<F>;
+
+	#[inline]
+	fn into_searcher(self) -> Self::Searcher {
+		BytePredicateSearcher(self)
+	}
+}
+
+impl<F: FnMut(u8) -> bool> RawSearcher for BytePredicateSearcher<F> {
+	#[inline]
+	fn search(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		let i = haystack.iter().position(|&b| (self.0)(b))?;
+		Some((i, i + 1))
+	}
+
+	#[inline]
+	fn search_back(&mut self, haystack: &[u8]) -> Option<(usize, usize)> {
+		let i = haystack.iter().rposition(|&b| (self.0)(b))?;
+		Some((i, i + 1))
+	}
+
+	#[inline]
+	fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+		haystack.first().is_some_and(|&b| (self.0)(b)).then_some(1)
+	}
+
+	#[inline]
+	fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+		haystack.last().is_some_and(|&b| (self.0)(b)).then_some(1)
+	}
+
+	#[inline]
+	fn count_matches(&mut self, haystack: &[u8]) -> usize {
+		haystack.iter().filter(|&&b| (self.0)(b)).count()
+	}
+}
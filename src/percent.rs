@@ -0,0 +1,115 @@
+// rawstring::percent
+
+use std::borrow::Cow;
+
+use crate::{
+	RawStr, RawString, bytes,
+	hex::{UPPER_HEX, hex_value},
+};
+
+impl RawStr {
+	/// Percent-encodes the bytes of the string that are in `set`,
+	/// replacing each of them with `%` followed by two uppercase hexadecimal digits.
+	///
+	/// The string is borrowed unchanged if none of its bytes need encoding.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{PercentEncodeSet, RawStr};
+	/// let path = RawStr::new(b"/files/my report\xFF.pdf");
+	/// assert_eq!(&*path.percent_encode(PercentEncodeSet::Path), "/files/my%20report%FF.pdf");
+	/// assert_eq!(&*path.percent_encode(PercentEncodeSet::Component), "%2Ffiles%2Fmy%20report%FF.pdf");
+	///
+	/// let query = RawStr::new("q=a&b");
+	/// assert_eq!(&*query.percent_encode(PercentEncodeSet::Query), "q=a&b");
+	/// ```
+	#[must_use]
+	pub fn percent_encode(&self, set: PercentEncodeSet) -> Cow<'_, RawStr> {
+		let Some(first) = self.0.iter().position(|&b| set.contains(b)) else {
+			return Cow::Borrowed(self);
+		};
+
+		let mut out = Vec::with_capacity(self.len() + 16);
+		out.extend_from_slice(&self.0[..first]);
+		for &b in &self.0[first..] {
+			if set.contains(b) {
+				out.extend_from_slice(&[b'%', UPPER_HEX[usize::from(b >> 4)], UPPER_HEX[usize::from(b & 0xF)]]);
+			} else {
+				out.push(b);
+			}
+		}
+		Cow::Owned(RawString(out))
+	}
+
+	/// Decodes percent-encoded bytes, replacing each `%` followed by two hexadecimal digits
+	/// with the byte they encode.
+	///
+	/// A `%` that is not followed by two hexadecimal digits is left as it is.
+	/// `+` is not decoded as a space.
+	/// The string is borrowed unchanged if it contains nothing to decode.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new("caf%C3%a9%20%FF 100%");
+	/// assert_eq!(&*raw.percent_decode(), b"caf\xC3\xA9 \xFF 100%");
+	/// ```
+	#[must_use]
+	pub fn percent_decode(&self) -> Cow<'_, RawStr> {
+		let Some(first) = bytes::find_byte(&self.0, b'%') else {
+			return Cow::Borrowed(self);
+		};
+
+		let mut out = Vec::with_capacity(self.len());
+		out.extend_from_slice(&self.0[..first]);
+		let mut rest = &self.0[first..];
+		while let Some(i) = bytes::find_byte(rest, b'%') {
+			out.extend_from_slice(&rest[..i]);
+			rest = &rest[i..];
+			match *rest {
+				[_, high, low, ..] if let (Some(high), Some(low)) = (hex_value(high), hex_value(low)) => {
+					out.push(high << 4 | low);
+					rest = &rest[3..];
+				}
+				_ => {
+					out.push(b'%');
+					rest = &rest[1..];
+				}
+			}
+		}
+		out.extend_from_slice(rest);
+		Cow::Owned(RawString(out))
+	}
+}
+
+/// The bytes to percent-encode, as defined by the URL and URI standards.
+///
+/// Used by [`RawStr::percent_encode`].
+/// Each set includes the bytes of the sets listed before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PercentEncodeSet {
+	/// ASCII control characters, `DEL` and all non-ASCII bytes.
+	Controls,
+	/// The bytes that cannot appear in a URL query: also space, `"`, `#`, `<` and `>`.
+	Query,
+	/// The bytes that cannot appear in a URL path: also `?`, `` ` ``, `{` and `}`.
+	Path,
+	/// Every byte except the unreserved characters of RFC 3986
+	/// (ASCII letters and digits, `-`, `.`, `_` and `~`),
+	/// for encoding a single path segment, query key or value.
+	Component,
+}
+
+impl PercentEncodeSet {
+	/// Returns `true` if `b` must be encoded.
+	#[inline]
+	fn contains(self, b: u8) -> bool {
+		let controls = b.is_ascii_control() || !b.is_ascii();
+		match self {
+			Self::Controls => controls,
+			Self::Query => controls || matches!(b, b' ' | b'"' | b'#' | b'<' | b'>'),
+			Self::Path => controls || matches!(b, b' ' | b'"' | b'#' | b'<' | b'>' | b'?' | b'`' | b'{' | b'}'),
+			Self::Component => !(b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')),
+		}
+	}
+}
@@ -0,0 +1,171 @@
+// rawstring::punycode
+
+use crate::{RawStr, RawString};
+
+// the parameters of RFC 3492
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+impl RawStr {
+	/// Encodes the string as Punycode, as specified by RFC 3492.
+	///
+	/// This is the raw encoding, without the `xn--` prefix of internationalized domain labels.
+	/// Returns [`None`] if the string is not valid UTF-8,
+	/// or is too long for the encoding not to overflow.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("münchen").to_punycode().as_deref(), Some("mnchen-3ya"));
+	/// assert_eq!(RawStr::new("日本語").to_punycode().as_deref(), Some("wgv71a119e"));
+	/// assert_eq!(RawStr::new(b"\xFF").to_punycode(), None);
+	/// ```
+	#[must_use]
+	pub fn to_punycode(&self) -> Option<String> {
+		let input: Vec<u32> = self.to_utf8_checked().ok()?.chars().map(u32::from).collect();
+		let mut out = String::with_capacity(self.len());
+		out.extend(input.iter().filter(|&&c| c < INITIAL_N).map(|&c| char::from(c as u8)));
+		let basic = out.len();
+		if basic > 0 {
+			out.push('-');
+		}
+
+		let mut n = INITIAL_N;
+		let mut delta: u32 = 0;
+		let mut bias = INITIAL_BIAS;
+		let mut handled = basic;
+		while handled < input.len() {
+			let m = input.iter().copied().filter(|&c| c >= n).min()?;
+			delta = delta.checked_add((m - n).checked_mul(u32::try_from(handled + 1).ok()?)?)?;
+			n = m;
+			for &c in &input {
+				if c < n {
+					delta = delta.checked_add(1)?;
+				}
+				if c == n {
+					let mut q = delta;
+					let mut k = BASE;
+					loop {
+						let t = threshold(k, bias);
+						if q < t {
+							break;
+						}
+						out.push(encode_digit(t + (q - t) % (BASE - t)));
+						q = (q - t) / (BASE - t);
+						k += BASE;
+					}
+					out.push(encode_digit(q));
+					bias = adapt(delta, u32::try_from(handled + 1).ok()?, handled == basic);
+					delta = 0;
+					handled += 1;
+				}
+			}
+			delta = delta.checked_add(1)?;
+			n += 1;
+		}
+		Some(out)
+	}
+}
+
+impl RawString {
+	/// Decodes a Punycode string, as specified by RFC 3492, into UTF-8.
+	///
+	/// This is the raw encoding, without the `xn--` prefix of internationalized domain labels.
+	/// Digits are case-insensitive.
+	/// Returns [`None`] if `encoded` is not valid Punycode.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// assert_eq!(RawString::from_punycode("mnchen-3ya").unwrap().as_ref(), "münchen");
+	/// assert_eq!(RawString::from_punycode("WGV71A119E").unwrap().as_ref(), "日本語");
+	/// assert_eq!(RawString::from_punycode("abc-"), Some(RawString::from("abc")));
+	/// assert_eq!(RawString::from_punycode("99999999999"), None);
+	/// ```
+	#[must_use]
+	pub fn from_punycode<B>(encoded: &B) -> Option<Self>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let encoded = encoded.as_ref();
+		let (basic, digits) = match encoded.iter().rposition(|&b| b == b'-') {
+			Some(i) => (&encoded[..i], &encoded[i + 1..]),
+			None => (&[][..], encoded),
+		};
+		if !basic.is_ascii() {
+			return None;
+		}
+		let mut out: Vec<char> = basic.iter().map(|&b| char::from(b)).collect();
+
+		let mut n = INITIAL_N;
+		let mut i: u32 = 0;
+		let mut bias = INITIAL_BIAS;
+		let mut digits = digits.iter();
+		while digits.len() > 0 {
+			let old_i = i;
+			let mut w: u32 = 1;
+			let mut k = BASE;
+			loop {
+				let digit = decode_digit(*digits.next()?)?;
+				i = i.checked_add(digit.checked_mul(w)?)?;
+				let t = threshold(k, bias);
+				if digit < t {
+					break;
+				}
+				w = w.checked_mul(BASE - t)?;
+				k += BASE;
+			}
+			let len = u32::try_from(out.len() + 1).ok()?;
+			bias = adapt(i - old_i, len, old_i == 0);
+			n = n.checked_add(i / len)?;
+			i %= len;
+			out.insert(i as usize, char::from_u32(n)?);
+			i += 1;
+		}
+		Some(Self(out.into_iter().collect::<String>().into_bytes()))
+	}
+}
+
+#[inline]
+fn threshold(k: u32, bias: u32) -> u32 {
+	if k <= bias {
+		T_MIN
+	} else if k >= bias + T_MAX {
+		T_MAX
+	} else {
+		k - bias
+	}
+}
+
+/// Adapts the bias after each delta, as specified by section 6.1 of RFC 3492.
+fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+	let mut delta = if first { delta / DAMP } else { delta / 2 };
+	delta += delta / points;
+	let mut k = 0;
+	while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+		delta /= BASE - T_MIN;
+		k += BASE;
+	}
+	k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+#[inline]
+fn encode_digit(digit: u32) -> char {
+	let digit = digit as u8;
+	char::from(if digit < 26 { b'a' + digit } else { b'0' + digit - 26 })
+}
+
+#[inline]
+fn decode_digit(b: u8) -> Option<u32> {
+	match b {
+		b'a'..=b'z' => Some(u32::from(b - b'a')),
+		b'A'..=b'Z' => Some(u32::from(b - b'A')),
+		b'0'..=b'9' => Some(u32::from(b - b'0') + 26),
+		_ => None,
+	}
+}
@@ -0,0 +1,132 @@
+// rawstring::quoted_printable
+
+use crate::{
+	RawStr, RawString, bytes,
+	hex::{UPPER_HEX, hex_value},
+};
+
+/// The maximum length of an encoded line, not counting the line break.
+const MAX_LINE_LEN: usize = 76;
+
+impl RawStr {
+	/// Encodes the string as quoted-printable, as specified by RFC 2045.
+	///
+	/// `\r\n` is kept as a line break, and every other byte outside of printable ASCII,
+	/// as well as `=` and a space or tab ending a line, is encoded as `=NN`.
+	/// Lines are kept to 76 characters with soft line breaks (`=\r\n`).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let body = RawStr::new(b"Caf\xC3\xA9 = 3\xE2\x82\xAC \r\nend\t");
+	/// assert_eq!(body.to_quoted_printable(), "Caf=C3=A9 =3D 3=E2=82=AC=20\r\nend=09");
+	///
+	/// let long = RawStr::new("a").repeat(80);
+	/// assert_eq!(long.as_ref().to_quoted_printable(), format!("{}=\r\n{}", "a".repeat(75), "a".repeat(5)));
+	/// ```
+	#[must_use]
+	pub fn to_quoted_printable(&self) -> String {
+		let mut out = String::with_capacity(self.len() + self.len() / 8);
+		let mut line_len = 0;
+		let mut rest = &self.0[..];
+		while let Some((&b, tail)) = rest.split_first() {
+			if b == b'\r' && tail.first() == Some(&b'\n') {
+				out.push_str("\r\n");
+				line_len = 0;
+				rest = &tail[1..];
+				continue;
+			}
+
+			let line_end = tail.is_empty() || tail.starts_with(b"\r\n");
+			let literal = match b {
+				b'=' => false,
+				b' ' | b'\t' => !line_end,
+				_ => b.is_ascii_graphic(),
+			};
+			let len = if literal { 1 } else { 3 };
+			// leave room for the `=` of a soft line break, unless this ends the line
+			let max = if line_end { MAX_LINE_LEN } else { MAX_LINE_LEN - 1 };
+			if line_len + len > max {
+				out.push_str("=\r\n");
+				line_len = 0;
+			}
+			if literal {
+				out.push(char::from(b));
+			} else {
+				out.push('=');
+				out.push(char::from(UPPER_HEX[usize::from(b >> 4)]));
+				out.push(char::from(UPPER_HEX[usize::from(b & 0xF)]));
+			}
+			line_len += len;
+			rest = tail;
+		}
+		out
+	}
+}
+
+impl RawString {
+	/// Decodes a quoted-printable string, as specified by RFC 2045.
+	///
+	/// `=NN` escapes are decoded, with hexadecimal digits in either case,
+	/// soft line breaks (`=` at the end of a line) are removed,
+	/// and so are the spaces and tabs ending a line.
+	/// Line breaks may be `\r\n` or `\n`, and hard line breaks are kept as they are.
+	/// A `=` that starts neither an escape nor a soft line break is kept as it is.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let raw = RawString::from_quoted_printable("Caf=C3=a9 =3D 3=E2=82=AC  \r\nso=\r\nft=\n=FF=Z\n");
+	/// assert_eq!(raw.as_ref(), b"Caf\xC3\xA9 = 3\xE2\x82\xAC\r\nsoft\xFF=Z\n");
+	/// ```
+	#[must_use]
+	pub fn from_quoted_printable<B>(encoded: &B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let mut out = Vec::with_capacity(encoded.as_ref().len());
+		let mut rest = encoded.as_ref();
+		loop {
+			let (line, line_break, next) = match bytes::find_byte(rest, b'\n') {
+				Some(i) => {
+					let start = if i > 0 && rest[i - 1] == b'\r' { i - 1 } else { i };
+					(&rest[..start], &rest[start..=i], Some(&rest[i + 1..]))
+				}
+				None => (rest, &[][..], None),
+			};
+
+			let end = line.iter().rposition(|&b| b != b' ' && b != b'\t').map_or(0, |i| i + 1);
+			match line[..end].strip_suffix(b"=") {
+				Some(line) => decode_line(line, &mut out),
+				None => {
+					decode_line(&line[..end], &mut out);
+					out.extend_from_slice(line_break);
+				}
+			}
+
+			match next {
+				Some(next) => rest = next,
+				None => break,
+			}
+		}
+		Self(out)
+	}
+}
+
+/// Decodes the `=NN` escapes of a line, appending it to `out`.
+fn decode_line(mut line: &[u8], out: &mut Vec<u8>) {
+	while let Some(i) = bytes::find_byte(line, b'=') {
+		out.extend_from_slice(&line[..i]);
+		match line.get(i + 1..i + 3).and_then(|digits| Some((hex_value(digits[0])?, hex_value(digits[1])?))) {
+			Some((high, low)) => {
+				out.push(high << 4 | low);
+				line = &line[i + 3..];
+			}
+			None => {
+				out.push(b'=');
+				line = &line[i + 1..];
+			}
+		}
+	}
+	out.extend_from_slice(line);
+}
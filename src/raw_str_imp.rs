@@ -7,6 +7,8 @@ use std::{
 	str::Utf8Error,
 };
 
+use crate::RawUtf8Error;
+
 /// A borrowed string slice that may or may not contain valid UTF-8.
 /// 
 /// [`RawStr`] serves as an alternative to Rust's [`str`] type
@@ -101,11 +103,28 @@ impl RawStr {
 	/// assert!(bad.to_utf8_checked().is_err());
 	/// ```
 	#[inline]
-	#[must_use]
 	pub const fn to_utf8_checked(&self) -> Result<&str, Utf8Error> {
 		str::from_utf8(&self.0)
 	}
 
+	/// Converts the [`RawStr`] to a [`str`] if it contains valid UTF-8.
+	/// Returns a [`RawUtf8Error`] if the bytes are not valid UTF-8,
+	/// which also gives access to the offending bytes.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("plain").to_str(), Ok("plain"));
+	///
+	/// let err = RawStr::new(b"ab\xC3(").to_str().unwrap_err();
+	/// assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+	/// assert_eq!(err.invalid_bytes(), b"\xC3");
+	/// ```
+	#[inline]
+	pub fn to_str(&self) -> Result<&str, RawUtf8Error<&RawStr>> {
+		str::from_utf8(&self.0).map_err(|error| RawUtf8Error { bytes: self, error })
+	}
+
 	/// Returns `true` if the [`RawStr`] contains valid UTF-8.
 	/// 
 	/// # Examples
@@ -122,6 +141,43 @@ impl RawStr {
 	pub const fn is_utf8(&self) -> bool {
 		self.to_utf8_checked().is_ok()
 	}
+
+	/// Returns the length of the longest prefix of the [`RawStr`] that is pure ASCII.
+	///
+	/// The bytes are scanned a machine word at a time, so this is cheap enough to
+	/// classify large buffers before picking a parsing strategy.
+	/// Checking the whole buffer is better done with `is_ascii`,
+	/// which is available through [`Deref`] and uses the same technique.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"plain ascii header \xFF binary");
+	/// assert_eq!(raw.ascii_prefix_len(), 19);
+	/// assert_eq!(RawStr::new("ascii").ascii_prefix_len(), 5);
+	/// ```
+	#[must_use]
+	pub fn ascii_prefix_len(&self) -> usize {
+		const WORD: usize = size_of::<usize>();
+		const HIGH_BITS: usize = usize::from_ne_bytes([0x80; WORD]);
+
+		let (words, _) = self.0.as_chunks::<WORD>();
+		let mut len = 0;
+		for word in words {
+			let high = usize::from_ne_bytes(*word) & HIGH_BITS;
+			if high != 0 {
+				// the lowest-addressed byte is the least significant one on little endian
+				let bits = if cfg!(target_endian = "little") {
+					high.trailing_zeros()
+				} else {
+					high.leading_zeros()
+				};
+				return len + bits as usize / 8;
+			}
+			len += WORD;
+		}
+		len + self.0[len..].iter().take_while(|b| b.is_ascii()).count()
+	}
 }
 
 impl const Deref for RawStr {
@@ -3,11 +3,11 @@
 use std::{
 	borrow::{Cow, Borrow, BorrowMut},
 	ops::{Deref, DerefMut},
-	string::FromUtf8Error,
 	fmt,
+	io,
 };
 
-use crate::RawStr;
+use crate::{InvalidUtf8, RawStr, RawUtf8Error};
 
 /// A mutable, growable string that may or may not contain valid UTF-8.
 /// 
@@ -42,6 +42,7 @@ impl RawString {
 	/// Returns a reference to the inner byte slice as a [`RawStr`].
 	#[inline]
 	#[must_use]
+	#[allow(clippy::should_implement_trait)]
 	pub fn as_ref(&self) -> &RawStr {
 		RawStr::from_bytes(&self.0)
 	}
@@ -50,6 +51,7 @@ impl RawString {
 	#[doc(hidden)]
 	#[inline]
 	#[must_use]
+	#[allow(clippy::should_implement_trait)]
 	pub fn as_mut(&mut self) -> &mut RawStr {
 		RawStr::from_bytes_mut(&mut self.0)
 	}
@@ -62,14 +64,68 @@ impl RawString {
 		Self(bytes)
 	}
 
+	/// Creates a [`RawString`] from the given bytes,
+	/// with each invalid UTF-8 sequence replaced by the replacement character (�)
+	/// or kept, as chosen by `invalid`.
+	///
+	/// With [`InvalidUtf8::Replace`] the result is always valid UTF-8.
+	/// The allocation is reused unless a sequence has to be replaced.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{InvalidUtf8, RawString};
+	/// let raw = RawString::from_utf8_lossy_owned(b"caf\xC3\xA9 \xFF\xC3".to_vec(), InvalidUtf8::Replace);
+	/// assert_eq!(raw.as_ref(), "café ��");
+	/// assert!(raw.is_utf8());
+	///
+	/// let raw = RawString::from_utf8_lossy_owned(b"caf\xC3\xA9 \xFF".to_vec(), InvalidUtf8::Keep);
+	/// assert_eq!(raw.as_ref(), b"caf\xC3\xA9 \xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn from_utf8_lossy_owned(bytes: Vec<u8>, invalid: InvalidUtf8) -> Self {
+		match invalid {
+			InvalidUtf8::Replace => Self(Self(bytes).into_string_lossy().into_bytes()),
+			InvalidUtf8::Keep => Self(bytes),
+		}
+	}
+
+	/// Creates a [`RawString`] by copying the given bytes,
+	/// with each invalid UTF-8 sequence replaced by the replacement character (�)
+	/// or kept, as chosen by `invalid`.
+	///
+	/// With [`InvalidUtf8::Replace`] the result is always valid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{InvalidUtf8, RawString};
+	/// let input: &[u8] = b"\xFEuser\xFF";
+	/// assert_eq!(RawString::from_str_lossy(input, InvalidUtf8::Replace).as_ref(), "�user�");
+	/// assert_eq!(RawString::from_str_lossy(input, InvalidUtf8::Keep).as_ref(), b"\xFEuser\xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn from_str_lossy<B>(bytes: &B, invalid: InvalidUtf8) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		match invalid {
+			InvalidUtf8::Replace => Self(String::from_utf8_lossy(bytes.as_ref()).into_owned().into_bytes()),
+			InvalidUtf8::Keep => Self(bytes.as_ref().to_vec()),
+		}
+	}
+
 	/// Converts the [`RawString`] into a [`String`] if it contains valid UTF-8.
-	/// Returns a [`FromUtf8Error`] if the bytes are not valid UTF-8.
+	/// Returns a [`RawUtf8Error`] if the bytes are not valid UTF-8,
+	/// from which the [`RawString`] can be recovered.
 	/// 
 	/// See [`String::from_utf8`].
 	#[inline]
-	#[must_use]
-	pub fn to_utf8_checked(self) -> Result<String, FromUtf8Error> {
-		String::from_utf8(self.0)
+	pub fn to_utf8_checked(self) -> Result<String, RawUtf8Error<RawString>> {
+		String::from_utf8(self.0).map_err(|e| RawUtf8Error {
+			error: e.utf8_error(),
+			bytes: RawString(e.into_bytes()),
+		})
 	}
 
 	/// Converts the [`RawString`] into a [`String`] without checking for valid UTF-8.
@@ -92,6 +148,31 @@ impl RawString {
 		String::from_utf8_lossy(&self.0)
 	}
 
+	/// Lossily converts the [`RawString`] into a [`String`], consuming it.
+	/// Invalid UTF-8 sequences are replaced with the replacement character (�).
+	///
+	/// If the bytes are already valid UTF-8, the allocation is reused without copying.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let valid = RawString::from("ready");
+	/// let ptr = valid.as_ptr();
+	/// let s = valid.into_string_lossy();
+	/// assert_eq!(s, "ready");
+	/// assert_eq!(s.as_ptr(), ptr);
+	///
+	/// assert_eq!(RawString::from(b"a\xFFb".as_slice()).into_string_lossy(), "a�b");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn into_string_lossy(self) -> String {
+		match String::from_utf8(self.0) {
+			Ok(s) => s,
+			Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+		}
+	}
+
 	/// Returns `true` if the [`RawString`] contains valid UTF-8.
 	/// 
 	/// See [`RawStr::is_utf8`].
@@ -174,6 +255,15 @@ impl BorrowMut<RawStr> for RawString {
 	}
 }
 
+impl ToOwned for RawStr {
+	type Owned = RawString;
+
+	#[inline]
+	fn to_owned(&self) -> RawString {
+		RawString(self.0.to_vec())
+	}
+}
+
 impl fmt::Debug for RawString {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -188,6 +278,76 @@ impl fmt::Display for RawString {
 	}
 }
 
+/// Formatting into a [`RawString`] appends the UTF-8 output to it, without an intermediate [`String`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawString;
+/// use std::fmt::Write;
+///
+/// let mut raw = RawString::from(b"\xFF ".as_slice());
+/// write!(raw, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// assert_eq!(raw.as_ref(), b"\xFF 1 + 2 = 3");
+/// ```
+impl fmt::Write for RawString {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.push_str(s);
+		Ok(())
+	}
+
+	#[inline]
+	fn write_char(&mut self, c: char) -> fmt::Result {
+		self.push_char(c);
+		Ok(())
+	}
+}
+
+/// Writing to a [`RawString`] appends the bytes to it, and never fails.
+///
+/// As with [`Vec<u8>`], `&mut RawString` is a writer too, through the blanket implementation for `&mut W`.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawString;
+/// use std::io::{IoSlice, Write};
+///
+/// let mut raw = RawString::new();
+/// raw.write_all(b"\xFF\xFE")?;
+/// raw.write_vectored(&[IoSlice::new(b"a"), IoSlice::new(b"b")])?;
+/// std::io::copy(&mut &b"cd"[..], &mut &mut raw)?;
+/// assert_eq!(raw.as_ref(), b"\xFF\xFEabcd");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+impl io::Write for RawString {
+	#[inline]
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.0.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	#[inline]
+	fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+		let len = bufs.iter().map(|buf| buf.len()).sum();
+		self.0.reserve(len);
+		for buf in bufs {
+			self.0.extend_from_slice(buf);
+		}
+		Ok(len)
+	}
+
+	#[inline]
+	fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+		self.0.extend_from_slice(buf);
+		Ok(())
+	}
+
+	#[inline]
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
 impl<T: Into<Vec<u8>>> From<T> for RawString {
 	#[inline]
 	fn from(value: T) -> Self {
@@ -196,10 +356,10 @@ impl<T: Into<Vec<u8>>> From<T> for RawString {
 }
 
 impl TryFrom<RawString> for String {
-	type Error = FromUtf8Error;
+	type Error = RawUtf8Error<RawString>;
 
 	#[inline]
-	fn try_from(this: RawString) -> Result<String, FromUtf8Error> {
-		String::from_utf8(this.0)
+	fn try_from(this: RawString) -> Result<String, Self::Error> {
+		this.to_utf8_checked()
 	}
 }
\ No newline at end of file
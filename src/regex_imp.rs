@@ -0,0 +1,202 @@
+// rawstring::regex_imp
+
+use std::borrow::Cow;
+
+use regex::bytes::{Captures, Regex, Replacer};
+
+use crate::{RawStr, RawString, bytes};
+
+impl RawStr {
+	/// Returns `true` if `regex` matches anywhere in the string.
+	///
+	/// Requires the `regex` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use regex::bytes::Regex;
+	///
+	/// let re = Regex::new(r"\d+").unwrap();
+	/// assert!(RawStr::new(b"\xFFid=42").regex_is_match(&re));
+	/// assert!(!RawStr::new(b"\xFFid=").regex_is_match(&re));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn regex_is_match(&self, regex: &Regex) -> bool {
+		regex.is_match(&self.0)
+	}
+
+	/// Returns the first match of `regex` and the byte offset at which it starts.
+	///
+	/// Requires the `regex` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use regex::bytes::Regex;
+	///
+	/// let re = Regex::new(r"\d+").unwrap();
+	/// let raw = RawStr::new(b"\xFFid=42;");
+	/// assert_eq!(raw.regex_find(&re), Some((4, RawStr::new("42"))));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn regex_find(&self, regex: &Regex) -> Option<(usize, &RawStr)> {
+		let m = regex.find(&self.0)?;
+		Some((m.start(), RawStr::from_bytes(m.as_bytes())))
+	}
+
+	/// Returns the capture groups of the first match of `regex`.
+	///
+	/// Requires the `regex` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use regex::bytes::Regex;
+	///
+	/// let re = Regex::new(r"(?-u)(?<key>\w+)=(?<value>[^;]*)").unwrap();
+	/// let raw = RawStr::new(b"name=\xFFbob;");
+	/// let caps = raw.regex_captures(&re).unwrap();
+	/// assert_eq!(caps.name("key"), Some(RawStr::new("name")));
+	/// assert_eq!(caps.get(2), Some(RawStr::new(b"\xFFbob")));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn regex_captures<'h>(&'h self, regex: &Regex) -> Option<RawCaptures<'h>> {
+		regex.captures(&self.0).map(RawCaptures)
+	}
+
+	/// Replaces the first match of `regex` with `replacement`.
+	///
+	/// `replacement` can be anything implementing [`Replacer`],
+	/// including [`&RawStr`](RawStr) and [`RawString`], which expand `$name` references
+	/// just like byte slices do.
+	///
+	/// Requires the `regex` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use regex::bytes::Regex;
+	///
+	/// let re = Regex::new(r"(\w+)@(\w+)").unwrap();
+	/// let raw = RawStr::new(b"\xFFuser@host");
+	/// assert_eq!(raw.regex_replace(&re, RawStr::new("$2:$1")).as_ref(), b"\xFFhost:user");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn regex_replace<R: Replacer>(&self, regex: &Regex, replacement: R) -> RawString {
+		RawString::from(regex.replace(&self.0, replacement))
+	}
+
+	/// Replaces every non-overlapping match of `regex` with `replacement`.
+	///
+	/// See [`regex_replace`](Self::regex_replace).
+	///
+	/// Requires the `regex` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use regex::bytes::Regex;
+	///
+	/// let re = Regex::new(r"(?-u)[\x80-\xFF]").unwrap();
+	/// let raw = RawStr::new(b"a\xFFb\xFEc");
+	/// assert_eq!(raw.regex_replace_all(&re, RawStr::new("?")).as_ref(), "a?b?c");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn regex_replace_all<R: Replacer>(&self, regex: &Regex, replacement: R) -> RawString {
+		RawString::from(regex.replace_all(&self.0, replacement))
+	}
+}
+
+/// The capture groups of a single regex match, as [`RawStr`] slices.
+///
+/// Created by [`RawStr::regex_captures`].
+#[derive(Debug)]
+pub struct RawCaptures<'h>(Captures<'h>);
+
+impl<'h> RawCaptures<'h> {
+	/// Returns the capture group at index `i`, if it participated in the match.
+	/// Group `0` is always the whole match.
+	#[inline]
+	#[must_use]
+	pub fn get(&self, i: usize) -> Option<&'h RawStr> {
+		self.0.get(i).map(|m| RawStr::from_bytes(m.as_bytes()))
+	}
+
+	/// Returns the capture group named `name`, if it participated in the match.
+	#[inline]
+	#[must_use]
+	pub fn name(&self, name: &str) -> Option<&'h RawStr> {
+		self.0.name(name).map(|m| RawStr::from_bytes(m.as_bytes()))
+	}
+
+	/// Returns the byte offset at which capture group `i` starts.
+	#[inline]
+	#[must_use]
+	pub fn start(&self, i: usize) -> Option<usize> {
+		self.0.get(i).map(|m| m.start())
+	}
+
+	/// Returns the number of capture groups in the regex, including group `0`.
+	#[inline]
+	#[must_use]
+	#[allow(clippy::len_without_is_empty)] // there is always at least group 0
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns an iterator over all capture groups, in order.
+	/// Groups that did not participate in the match are yielded as [`None`].
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = Option<&'h RawStr>> + '_ {
+		self.0.iter().map(|m| m.map(|m| RawStr::from_bytes(m.as_bytes())))
+	}
+
+	/// Returns the underlying [`Captures`].
+	#[inline]
+	#[must_use]
+	pub fn as_captures(&self) -> &Captures<'h> {
+		&self.0
+	}
+}
+
+impl Replacer for &RawStr {
+	#[inline]
+	fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut Vec<u8>) {
+		(&self.0).replace_append(caps, dst)
+	}
+
+	#[inline]
+	fn no_expansion(&mut self) -> Option<Cow<'_, [u8]>> {
+		// same shortcut as the byte slice implementation
+		bytes::find_byte(&self.0, b'$').is_none().then_some(Cow::Borrowed(&self.0))
+	}
+}
+
+impl Replacer for &RawString {
+	#[inline]
+	fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut Vec<u8>) {
+		self.0.as_slice().replace_append(caps, dst)
+	}
+
+	#[inline]
+	fn no_expansion(&mut self) -> Option<Cow<'_, [u8]>> {
+		bytes::find_byte(&self.0, b'$').is_none().then_some(Cow::Borrowed(&self.0))
+	}
+}
+
+impl Replacer for RawString {
+	#[inline]
+	fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut Vec<u8>) {
+		self.0.as_slice().replace_append(caps, dst)
+	}
+
+	#[inline]
+	fn no_expansion(&mut self) -> Option<Cow<'_, [u8]>> {
+		bytes::find_byte(&self.0, b'$').is_none().then_some(Cow::Borrowed(&self.0))
+	}
+}
@@ -0,0 +1,330 @@
+// rawstring::replace
+
+use std::ops::RangeBounds;
+
+use crate::{
+	RawStr, RawString,
+	pattern::{RawPattern, RawSearcher},
+	search::Searching,
+	utf8,
+};
+
+impl RawStr {
+	/// Replaces all matches of `from` with `to`, returning a new [`RawString`].
+	///
+	/// See [`RawPattern`] for the kinds of patterns accepted.
+	/// The matches and the replacement may have different lengths.
+	/// As with [`str::replace`], an empty pattern matches between every byte.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\r\nb\xFF\r\nc");
+	/// assert_eq!(raw.replace("\r\n", b"\n").as_ref(), b"a\nb\xFF\nc");
+	/// assert_eq!(raw.replace(b'\xFF', "\u{FFFD}").as_ref(), "a\r\nb\u{FFFD}\r\nc");
+	/// assert_eq!(RawStr::new("ab").replace("", "-").as_ref(), "-a-b-");
+	/// ```
+	#[must_use]
+	pub fn replace<P, M, B>(&self, from: P, to: &B) -> RawString
+	where
+		P: RawPattern<M>,
+		B: ?Sized + AsRef<[u8]>
+	{
+		self.replacen(from, to, usize::MAX)
+	}
+
+	/// Replaces the first `count` matches of `from` with `to`, returning a new [`RawString`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFF-\xFF-\xFF");
+	/// assert_eq!(raw.replacen(b'\xFF', "??", 2).as_ref(), b"??-??-\xFF");
+	/// ```
+	#[must_use]
+	pub fn replacen<P, M, B>(&self, from: P, to: &B, count: usize) -> RawString
+	where
+		P: RawPattern<M>,
+		B: ?Sized + AsRef<[u8]>
+	{
+		let to = to.as_ref();
+		let mut out = Vec::with_capacity(self.len());
+		let mut last_end = 0;
+		let mut matches = Searching::new(self, from.into_searcher());
+		for (start, end) in std::iter::from_fn(|| matches.next_match()).take(count) {
+			out.extend_from_slice(&self.0[last_end..start]);
+			out.extend_from_slice(to);
+			last_end = end;
+		}
+		out.extend_from_slice(&self.0[last_end..]);
+		RawString::from(out)
+	}
+
+	/// Replaces all non-overlapping occurrences of `from` with `to` in place,
+	/// without checking that they have the same length.
+	/// Returns the number of replacements.
+	///
+	/// See [`RawString::replace_in_place`] for a safe version.
+	///
+	/// # Safety
+	/// `from` and `to` must have the same length.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut buf = *b"token=\xFFsecret\xFF;";
+	/// let raw = RawStr::new_mut(&mut buf);
+	/// // SAFETY: both needles are one byte long
+	/// assert_eq!(unsafe { raw.replace_in_place_unchecked(b"\xFF", b"*") }, 2);
+	/// assert_eq!(raw, "token=*secret*;");
+	/// ```
+	pub unsafe fn replace_in_place_unchecked<B1, B2>(&mut self, from: &B1, to: &B2) -> usize
+	where
+		B1: ?Sized + AsRef<[u8]>,
+		B2: ?Sized + AsRef<[u8]>
+	{
+		let (from, to) = (from.as_ref(), to.as_ref());
+		debug_assert_eq!(from.len(), to.len());
+		if from.is_empty() {
+			return 0;
+		}
+
+		let mut count = 0;
+		let mut position = 0;
+		while let Some(i) = crate::bytes::find(&self.0[position..], from) {
+			let start = position + i;
+			// SAFETY: the match is in bounds, and the caller guarantees `to` has its length
+			unsafe {
+				std::ptr::copy_nonoverlapping(to.as_ptr(), self.0.as_mut_ptr().add(start), to.len());
+			}
+			position = start + from.len();
+			count += 1;
+		}
+		count
+	}
+}
+
+impl RawString {
+	/// Replaces all non-overlapping occurrences of `from` with `to` in place,
+	/// without allocating. Returns the number of replacements.
+	///
+	/// # Panics
+	/// Panics if `from` and `to` have different lengths.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"user=alice pass=hunter2\xFF".as_slice());
+	/// assert_eq!(raw.replace_in_place("hunter2", "*******"), 1);
+	/// assert_eq!(raw.as_ref(), b"user=alice pass=*******\xFF");
+	/// ```
+	pub fn replace_in_place<B1, B2>(&mut self, from: &B1, to: &B2) -> usize
+	where
+		B1: ?Sized + AsRef<[u8]>,
+		B2: ?Sized + AsRef<[u8]>
+	{
+		let (from, to) = (from.as_ref(), to.as_ref());
+		assert_eq!(from.len(), to.len(), "replacement must have the same length as the needle");
+		// SAFETY: the lengths were just checked
+		unsafe { self.as_mut().replace_in_place_unchecked(from, to) }
+	}
+
+	/// Replaces the given byte range with `replacement`, which may have a different length.
+	///
+	/// This is the [`RawString`] counterpart of [`String::replace_range`].
+	///
+	/// # Panics
+	/// Panics if the range is out of bounds or its start is after its end.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"GET /\xFF HTTP/1.1".as_slice());
+	/// raw.replace_range(4..6, "/index.html");
+	/// assert_eq!(raw.as_ref(), "GET /index.html HTTP/1.1");
+	/// raw.replace_range(..3, b"HEAD");
+	/// assert_eq!(raw.as_ref(), "HEAD /index.html HTTP/1.1");
+	/// ```
+	#[inline]
+	pub fn replace_range<R, B>(&mut self, range: R, replacement: &B)
+	where
+		R: RangeBounds<usize>,
+		B: ?Sized + AsRef<[u8]>
+	{
+		self.0.splice(range, replacement.as_ref().iter().copied());
+	}
+
+	/// Removes all non-overlapping matches of `pattern` in place.
+	///
+	/// The remaining bytes are moved down in a single pass, without allocating.
+	/// Empty matches remove nothing.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"a\r\nb\xFF\r\n\0\0".as_slice());
+	/// raw.remove_matches(b'\r');
+	/// raw.remove_matches(b'\0');
+	/// assert_eq!(raw.as_ref(), b"a\nb\xFF\n");
+	///
+	/// let mut raw = RawString::from("1, 2, 3");
+	/// raw.remove_matches(", ");
+	/// assert_eq!(raw.as_ref(), "123");
+	/// ```
+	pub fn remove_matches<P, M>(&mut self, pattern: P)
+	where
+		P: RawPattern<M>
+	{
+		// dropping the iterator removes all the matches
+		drop(self.drain_matches(pattern));
+	}
+
+	/// Removes all non-overlapping matches of `pattern` in place,
+	/// returning an iterator over the removed matches.
+	///
+	/// Like [`remove_matches`](Self::remove_matches), the remaining bytes are moved down
+	/// in a single pass as the iterator advances, and empty matches remove nothing.
+	/// When the iterator is dropped, all the matches not yet yielded are removed too.
+	/// If it is leaked instead, the contents of the string are unspecified.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"line\r\n\xFF\r\n".as_slice());
+	/// assert_eq!(raw.drain_matches(b'\r').count(), 2);
+	/// assert_eq!(raw.as_ref(), b"line\n\xFF\n");
+	///
+	/// // the matches left are removed when the iterator is dropped
+	/// let mut raw = RawString::from(b"\x01\xFFa\x01b\x01".as_slice());
+	/// let mut markers = raw.drain_matches(b'\x01');
+	/// assert_eq!(markers.next().unwrap().as_ref(), b"\x01");
+	/// drop(markers);
+	/// assert_eq!(raw.as_ref(), b"\xFFab");
+	///
+	/// let mut raw = RawString::from("a<br>b<br>c");
+	/// let removed: Vec<RawString> = raw.drain_matches("<br>").collect();
+	/// assert_eq!(removed, ["<br>", "<br>"].map(RawString::from));
+	/// assert_eq!(raw.as_ref(), "abc");
+	/// ```
+	pub fn drain_matches<P, M>(&mut self, pattern: P) -> DrainMatches<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		DrainMatches {
+			string: self,
+			searcher: pattern.into_searcher(),
+			read: 0,
+			write: 0,
+			position: 0,
+		}
+	}
+
+	/// Keeps only the characters for which `f` returns `true`, removing the others in place.
+	///
+	/// The string is decoded as UTF-8, and `invalid` decides what happens to
+	/// each maximal invalid subpart, which is never passed to `f`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{InvalidBytes, RawString};
+	/// let mut raw = RawString::from(b"\x1B[1mbold\x07 \xFF\xC3\xA9t\xC3\xA9".as_slice());
+	/// raw.retain_chars(|c| !c.is_control(), InvalidBytes::Keep);
+	/// assert_eq!(raw.as_ref(), b"[1mbold \xFF\xC3\xA9t\xC3\xA9");
+	/// raw.retain_chars(char::is_alphabetic, InvalidBytes::Remove);
+	/// assert_eq!(raw.as_ref(), "mboldété");
+	/// ```
+	pub fn retain_chars<F>(&mut self, mut f: F, invalid: InvalidBytes)
+	where
+		F: FnMut(char) -> bool
+	{
+		let mut read = 0;
+		let mut write = 0;
+		while let Some((c, len)) = utf8::decode_first(&self.0[read..]) {
+			let keep = match c {
+				Some(c) => f(c),
+				None => invalid == InvalidBytes::Keep,
+			};
+			if keep {
+				self.0.copy_within(read..read + len, write);
+				write += len;
+			}
+			read += len;
+		}
+		self.0.truncate(write);
+	}
+}
+
+/// What to do with invalid UTF-8 when filtering the characters of a string.
+///
+/// Used by [`RawString::retain_chars`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InvalidBytes {
+	/// Keep invalid bytes.
+	Keep,
+	/// Remove invalid bytes.
+	Remove,
+}
+
+/// What to do with invalid UTF-8 sequences when creating a string from untrusted bytes.
+///
+/// Used by [`RawString::from_utf8_lossy_owned`] and [`RawString::from_str_lossy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InvalidUtf8 {
+	/// Replace each invalid sequence with the replacement character (�).
+	Replace,
+	/// Keep invalid sequences as they are.
+	Keep,
+}
+
+/// An iterator removing the matches of a pattern from a [`RawString`] and yielding them.
+///
+/// Created by [`RawString::drain_matches`].
+#[derive(Debug)]
+pub struct DrainMatches<'a, S: RawSearcher> {
+	string: &'a mut RawString,
+	searcher: S,
+	// bytes before `read` have been processed, and the kept ones moved before `write`
+	read: usize,
+	write: usize,
+	position: usize,
+}
+
+impl<S: RawSearcher> DrainMatches<'_, S> {
+	/// Finds the next non-empty match and moves the kept bytes before it into place.
+	fn next_match(&mut self) -> Option<(usize, usize)> {
+		let bytes = &mut self.string.0;
+		loop {
+			let (start, end) = bytes.get(self.position..).and_then(|rest| self.searcher.search(rest))?;
+			let (start, end) = (self.position + start, self.position + end);
+			if start == end {
+				self.position = end + 1;
+				continue;
+			}
+			bytes.copy_within(self.read..start, self.write);
+			self.write += start - self.read;
+			self.read = end;
+			self.position = end;
+			return Some((start, end));
+		}
+	}
+}
+
+impl<S: RawSearcher> Iterator for DrainMatches<'_, S> {
+	type Item = RawString;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = self.next_match()?;
+		Some(RawString(self.string.0[start..end].to_vec()))
+	}
+}
+
+impl<S: RawSearcher> Drop for DrainMatches<'_, S> {
+	fn drop(&mut self) {
+		while self.next_match().is_some() {}
+		let bytes = &mut self.string.0;
+		let len = bytes.len();
+		bytes.copy_within(self.read..len, self.write);
+		bytes.truncate(self.write + len - self.read);
+	}
+}
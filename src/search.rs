@@ -0,0 +1,924 @@
+// rawstring::search
+
+use std::ops::{Bound, RangeBounds};
+
+use crate::{
+	RawStr,
+	RawString,
+	bytes,
+	pattern::{RawPattern, RawSearcher},
+};
+
+impl RawStr {
+	/// Returns the byte offset of the first match of `pattern`,
+	/// or [`None`] if it does not match.
+	///
+	/// See [`RawPattern`] for the kinds of patterns accepted.
+	/// An empty byte string matches at offset `0`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"key=\xFF=value");
+	/// assert_eq!(raw.find("="), Some(3));
+	/// assert_eq!(raw.find(b"\xFF"), Some(4));
+	/// assert_eq!(raw.find(RawStr::new("value")), Some(6));
+	/// assert_eq!(raw.find(|b: u8| b > 0x7F), Some(4));
+	/// assert_eq!(raw.find("missing"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find<P, M>(&self, pattern: P) -> Option<usize>
+	where
+		P: RawPattern<M>
+	{
+		pattern.into_searcher().search(&self.0).map(|(start, _)| start)
+	}
+
+	/// Returns the byte offset of the last match of `pattern`,
+	/// or [`None`] if it does not match.
+	///
+	/// See [`RawPattern`] for the kinds of patterns accepted.
+	/// An empty byte string matches at offset `self.len()`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"key=\xFF=value");
+	/// assert_eq!(raw.rfind("="), Some(5));
+	/// assert_eq!(raw.rfind(&['k', 'v']), Some(6));
+	/// assert_eq!(raw.rfind(""), Some(raw.len()));
+	/// assert_eq!(raw.rfind("missing"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind<P, M>(&self, pattern: P) -> Option<usize>
+	where
+		P: RawPattern<M>
+	{
+		pattern.into_searcher().search_back(&self.0).map(|(start, _)| start)
+	}
+
+	/// Returns the byte offset of the first match of `pattern` within `range`.
+	///
+	/// The returned offset is relative to the start of the whole string, not the range,
+	/// so it can be used directly by parsers that track absolute positions.
+	///
+	/// # Panics
+	/// Panics if `range` is out of bounds, like slicing would.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a=1;b=\xFF;c=3");
+	/// assert_eq!(raw.find_in(4.., b'='), Some(5));
+	/// assert_eq!(raw.find_in(..3, b';'), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_in<R, P, M>(&self, range: R, pattern: P) -> Option<usize>
+	where
+		R: RangeBounds<usize>,
+		P: RawPattern<M>
+	{
+		let (offset, slice) = self.slice_range(range);
+		pattern.into_searcher().search(slice).map(|(start, _)| offset + start)
+	}
+
+	/// Returns the byte offset of the last match of `pattern` within `range`.
+	///
+	/// See [`find_in`](Self::find_in).
+	///
+	/// # Panics
+	/// Panics if `range` is out of bounds, like slicing would.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a=1;b=\xFF;c=3");
+	/// assert_eq!(raw.rfind_in(..10, b';'), Some(7));
+	/// assert_eq!(raw.rfind_in(4..8, "="), Some(5));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_in<R, P, M>(&self, range: R, pattern: P) -> Option<usize>
+	where
+		R: RangeBounds<usize>,
+		P: RawPattern<M>
+	{
+		let (offset, slice) = self.slice_range(range);
+		pattern.into_searcher().search_back(slice).map(|(start, _)| offset + start)
+	}
+
+	/// Slices the bytes by `range`, returning the slice and the offset it starts at.
+	#[inline]
+	fn slice_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, &[u8]) {
+		let offset = match range.start_bound() {
+			Bound::Included(&start) => start,
+			Bound::Excluded(&start) => start + 1,
+			Bound::Unbounded => 0,
+		};
+		(offset, &self.0[(range.start_bound().cloned(), range.end_bound().cloned())])
+	}
+
+	/// Returns the byte offset of the first occurrence of the character `c`.
+	///
+	/// The character is searched for by its UTF-8 encoding.
+	/// A match can only start at a lead byte, which always begins a new sequence,
+	/// so matches are never reported inside an unrelated multibyte sequence,
+	/// even when the surrounding bytes are invalid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// // 0xA9 is the last byte of 'é', but is not a match for '©' (0xC2 0xA9)
+	/// let raw = RawStr::new(b"\xC3\xA9\xFF\xC2\xA9");
+	/// assert_eq!(raw.find_char('©'), Some(3));
+	/// assert_eq!(raw.find_char('é'), Some(0));
+	/// assert_eq!(raw.find_char('\u{FFFD}'), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_char(&self, c: char) -> Option<usize> {
+		self.find(c)
+	}
+
+	/// Returns the byte offset of the last occurrence of the character `c`.
+	///
+	/// See [`find_char`](Self::find_char).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC3\xA9t\xC3\xA9\xFF");
+	/// assert_eq!(raw.rfind_char('é'), Some(3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_char(&self, c: char) -> Option<usize> {
+		self.rfind(c)
+	}
+
+	/// Returns the byte offset of the first character accepted by `predicate`.
+	///
+	/// Only valid UTF-8 sequences are decoded and passed to `predicate`;
+	/// invalid bytes are skipped.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"ab\xFF\xC3\xA9");
+	/// assert_eq!(raw.find_char_by(|c| !c.is_ascii()), Some(3));
+	/// assert_eq!(raw.find_char_by(char::is_numeric), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_char_by<F>(&self, predicate: F) -> Option<usize>
+	where
+		F: FnMut(char) -> bool
+	{
+		self.find(predicate)
+	}
+
+	/// Returns the byte offset of the last character accepted by `predicate`.
+	///
+	/// See [`find_char_by`](Self::find_char_by).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a1b2\xFF");
+	/// assert_eq!(raw.rfind_char_by(|c| c.is_ascii_digit()), Some(3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_char_by<F>(&self, predicate: F) -> Option<usize>
+	where
+		F: FnMut(char) -> bool
+	{
+		self.rfind(predicate)
+	}
+
+	/// Returns `true` if the string starts with a match of `pattern`.
+	///
+	/// Unlike `[u8]::starts_with`, this accepts any [`RawPattern`];
+	/// character patterns are matched against their UTF-8 encoding.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC3\xA9t\xC3\xA9\xFF");
+	/// assert!(raw.starts_with('é'));
+	/// assert!(raw.starts_with("ét"));
+	/// assert!(raw.starts_with(|c: char| c.is_alphabetic()));
+	/// assert!(!raw.starts_with(b't'));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn starts_with<P, M>(&self, pattern: P) -> bool
+	where
+		P: RawPattern<M>
+	{
+		pattern.into_searcher().match_prefix(&self.0).is_some()
+	}
+
+	/// Returns `true` if the string ends with a match of `pattern`.
+	///
+	/// Unlike `[u8]::ends_with`, this accepts any [`RawPattern`];
+	/// character patterns are matched against their UTF-8 encoding.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFFcaf\xC3\xA9");
+	/// assert!(raw.ends_with('é'));
+	/// assert!(raw.ends_with(b"f\xC3\xA9"));
+	/// assert!(!raw.ends_with(|c: char| c.is_ascii()));
+	///
+	/// // invalid bytes never match a character predicate
+	/// assert!(!RawStr::new(b"a\xFF").ends_with(|_: char| true));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn ends_with<P, M>(&self, pattern: P) -> bool
+	where
+		P: RawPattern<M>
+	{
+		pattern.into_searcher().match_suffix(&self.0).is_some()
+	}
+
+	/// Returns the string with a leading match of `pattern` removed,
+	/// or [`None`] if the string does not start with a match.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let frame = RawStr::new(b"\x02payload\xFF\x03");
+	/// let body = frame.strip_prefix(b'\x02').and_then(|r| r.strip_suffix(b'\x03'));
+	/// assert_eq!(body, Some(RawStr::new(b"payload\xFF")));
+	/// assert_eq!(frame.strip_prefix("payload"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn strip_prefix<P, M>(&self, pattern: P) -> Option<&RawStr>
+	where
+		P: RawPattern<M>
+	{
+		let len = pattern.into_searcher().match_prefix(&self.0)?;
+		Some(RawStr::from_bytes(&self.0[len..]))
+	}
+
+	/// Returns the string with a trailing match of `pattern` removed,
+	/// or [`None`] if the string does not end with a match.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"line\xFF\r\n");
+	/// assert_eq!(raw.strip_suffix("\r\n"), Some(RawStr::new(b"line\xFF")));
+	/// assert_eq!(raw.strip_suffix(b'\r'), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn strip_suffix<P, M>(&self, pattern: P) -> Option<&RawStr>
+	where
+		P: RawPattern<M>
+	{
+		let len = pattern.into_searcher().match_suffix(&self.0)?;
+		Some(RawStr::from_bytes(&self.0[..self.len() - len]))
+	}
+
+	/// Returns the earliest occurrence of any of the `needles`,
+	/// as its byte offset and the index of the needle that matched.
+	///
+	/// If several needles match at the same offset, the one listed first wins.
+	/// Each needle is searched for separately, which is fast for a handful of needles;
+	/// for many needles, see `RawStrSetMatcher` (requires the `aho-corasick` feature).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"data\xFF\r\nmore\n");
+	/// assert_eq!(raw.find_any(&["\n", "\r\n"]), Some((5, 1)));
+	/// assert_eq!(raw.find_any(&["\t", ";"]), None);
+	/// ```
+	#[must_use]
+	pub fn find_any<B>(&self, needles: &[B]) -> Option<(usize, usize)>
+	where
+		B: AsRef<[u8]>
+	{
+		let mut best: Option<(usize, usize)> = None;
+		for (index, needle) in needles.iter().enumerate() {
+			let needle = needle.as_ref();
+			// only look where an earlier match could still be found
+			let limit = best.map_or(self.len(), |(offset, _)| (offset + needle.len()).min(self.len()));
+			if let Some(offset) = bytes::find(&self.0[..limit], needle)
+				&& best.is_none_or(|(best_offset, _)| offset < best_offset)
+			{
+				best = Some((offset, index));
+			}
+		}
+		best
+	}
+
+	/// Returns `true` if any of the `needles` occur in the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"GET /\xFF HTTP/1.1");
+	/// assert!(raw.contains_any(&["POST", "GET"]));
+	/// assert!(!raw.contains_any(&["PUT", "DELETE"]));
+	/// ```
+	#[must_use]
+	pub fn contains_any<B>(&self, needles: &[B]) -> bool
+	where
+		B: AsRef<[u8]>
+	{
+		needles.iter().any(|needle| bytes::find(&self.0, needle.as_ref()).is_some())
+	}
+
+	/// Returns the byte offset of the first occurrence of `needle`,
+	/// ignoring ASCII case differences.
+	///
+	/// Bytes outside the ASCII range must match exactly.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let headers = RawStr::new(b"Host: a\r\nCONTENT-type: \xFF\r\n");
+	/// assert_eq!(headers.find_ignore_ascii_case("content-type:"), Some(9));
+	/// assert_eq!(headers.find_ignore_ascii_case("accept"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_ignore_ascii_case<B>(&self, needle: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		bytes::find_ignore_ascii_case(&self.0, needle.as_ref())
+	}
+
+	/// Returns the byte offset of the last occurrence of `needle`,
+	/// ignoring ASCII case differences.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"[Core]\n[\xFF]\n[CORE]");
+	/// assert_eq!(raw.rfind_ignore_ascii_case("[core]"), Some(11));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_ignore_ascii_case<B>(&self, needle: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		bytes::rfind_ignore_ascii_case(&self.0, needle.as_ref())
+	}
+
+	/// Returns `true` if `needle` occurs in the string, ignoring ASCII case differences.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"Connection: Keep-Alive\xFF");
+	/// assert!(raw.contains_ignore_ascii_case("keep-alive"));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn contains_ignore_ascii_case<B>(&self, needle: &B) -> bool
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		self.find_ignore_ascii_case(needle).is_some()
+	}
+
+	/// Returns `true` if the string starts with `prefix`, ignoring ASCII case differences.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let line = RawStr::new(b"content-LENGTH: 42\xFF");
+	/// assert!(line.starts_with_ignore_ascii_case("Content-Length:"));
+	/// assert!(!line.starts_with_ignore_ascii_case("Content-Type:"));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn starts_with_ignore_ascii_case<B>(&self, prefix: &B) -> bool
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let prefix = prefix.as_ref();
+		self.0.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+	}
+
+	/// Returns `true` if the string ends with `suffix`, ignoring ASCII case differences.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new(b"\xFFPHOTO.JPG").ends_with_ignore_ascii_case(".jpg"));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn ends_with_ignore_ascii_case<B>(&self, suffix: &B) -> bool
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let suffix = suffix.as_ref();
+		self.len() >= suffix.len() && self.0[self.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+	}
+
+	/// Returns an iterator over the byte offsets of all non-overlapping matches of `pattern`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a,b\xFF,c");
+	/// let offsets: Vec<usize> = raw.find_iter(b',').collect();
+	/// assert_eq!(offsets, [1, 4]);
+	/// ```
+	#[inline]
+	pub fn find_iter<P, M>(&self, pattern: P) -> FindIter<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		FindIter(Searching::new(self, pattern.into_searcher()))
+	}
+
+	/// Returns an iterator over all non-overlapping matches of `pattern`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"id=1 \xFF id=22");
+	/// let digits: Vec<&RawStr> = raw.matches(|b: u8| b.is_ascii_digit()).collect();
+	/// assert_eq!(digits, ["1", "2", "2"]);
+	/// ```
+	#[inline]
+	pub fn matches<P, M>(&self, pattern: P) -> Matches<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		Matches(Searching::new(self, pattern.into_searcher()))
+	}
+
+	/// Returns an iterator over all non-overlapping matches of `pattern`,
+	/// together with the byte offset at which each one starts.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"abXYab\xFFab");
+	/// let found: Vec<(usize, &RawStr)> = raw.match_indices("ab").collect();
+	/// assert_eq!(found, [(0, RawStr::new("ab")), (4, RawStr::new("ab")), (7, RawStr::new("ab"))]);
+	/// ```
+	#[inline]
+	pub fn match_indices<P, M>(&self, pattern: P) -> MatchIndices<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		MatchIndices(Searching::new(self, pattern.into_searcher()))
+	}
+
+	/// Returns an iterator over all non-overlapping matches of `pattern`,
+	/// starting from the end of the string.
+	///
+	/// Matches are searched for backwards, so for overlapping patterns
+	/// the matches found may differ from those of [`matches`](Self::matches).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"rec1\x1Erec2\x1E\xFFrec3");
+	/// let last: Vec<&RawStr> = raw.rmatches(|b: u8| b.is_ascii_digit()).take(2).collect();
+	/// assert_eq!(last, ["3", "2"]);
+	///
+	/// let overlapping = RawStr::new("aaa");
+	/// assert_eq!(overlapping.rmatch_indices("aa").collect::<Vec<_>>(), [(1, RawStr::new("aa"))]);
+	/// ```
+	#[inline]
+	pub fn rmatches<P, M>(&self, pattern: P) -> RMatches<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		RMatches(SearchingBack::new(self, pattern.into_searcher()))
+	}
+
+	/// Returns an iterator over all non-overlapping matches of `pattern`
+	/// and their byte offsets, starting from the end of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\x1Eb\x1E\xFFc");
+	/// let offsets: Vec<usize> = raw.rmatch_indices(b'\x1E').map(|(i, _)| i).collect();
+	/// assert_eq!(offsets, [3, 1]);
+	/// ```
+	#[inline]
+	pub fn rmatch_indices<P, M>(&self, pattern: P) -> RMatchIndices<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		RMatchIndices(SearchingBack::new(self, pattern.into_searcher()))
+	}
+
+	/// Returns the number of non-overlapping matches of `pattern`.
+	///
+	/// This is equivalent to `self.matches(pattern).count()`,
+	/// but lets the pattern's searcher count without producing each match.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"aaaa\xFF\n\n");
+	/// assert_eq!(raw.count_matches(b'\n'), 2);
+	/// assert_eq!(raw.count_matches("aa"), 2);
+	/// assert_eq!(raw.count_matches(""), raw.len() + 1);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn count_matches<P, M>(&self, pattern: P) -> usize
+	where
+		P: RawPattern<M>
+	{
+		pattern.into_searcher().count_matches(&self.0)
+	}
+
+	/// Returns the number of matches of `pattern`, including overlapping ones.
+	///
+	/// A new match is searched for starting one byte after the start of the previous one.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"aaaa\xFF");
+	/// assert_eq!(raw.count_matches_overlapping("aa"), 3);
+	/// assert_eq!(raw.count_matches("aa"), 2);
+	/// ```
+	#[must_use]
+	pub fn count_matches_overlapping<P, M>(&self, pattern: P) -> usize
+	where
+		P: RawPattern<M>
+	{
+		let mut searcher = pattern.into_searcher();
+		let mut count = 0;
+		let mut position = 0;
+		while let Some((start, _)) = self.0.get(position..).and_then(|rest| searcher.search(rest)) {
+			count += 1;
+			position += start + 1;
+		}
+		count
+	}
+
+	/// Returns the byte offset of the first occurrence of `byte`.
+	///
+	/// This is accelerated with `memchr` when the `memchr` feature (enabled by default) is on.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"line one\nline two\n");
+	/// assert_eq!(raw.find_byte(b'\n'), Some(8));
+	/// assert_eq!(raw.find_byte(b'\0'), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_byte(&self, byte: u8) -> Option<usize> {
+		bytes::find_byte(&self.0, byte)
+	}
+
+	/// Returns the byte offset of the last occurrence of `byte`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"line one\nline two\n");
+	/// assert_eq!(raw.rfind_byte(b'\n'), Some(17));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_byte(&self, byte: u8) -> Option<usize> {
+		bytes::rfind_byte(&self.0, byte)
+	}
+
+	/// Returns the byte offset of the first occurrence of either `b1` or `b2`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\r\nb");
+	/// assert_eq!(raw.find_byte2(b'\n', b'\r'), Some(1));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_byte2(&self, b1: u8, b2: u8) -> Option<usize> {
+		bytes::find_byte2(&self.0, b1, b2)
+	}
+
+	/// Returns the byte offset of the last occurrence of either `b1` or `b2`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\r\nb");
+	/// assert_eq!(raw.rfind_byte2(b'\n', b'\r'), Some(2));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_byte2(&self, b1: u8, b2: u8) -> Option<usize> {
+		bytes::rfind_byte2(&self.0, b1, b2)
+	}
+
+	/// Returns the byte offset of the first occurrence of any of `b1`, `b2` or `b3`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"key: value; next");
+	/// assert_eq!(raw.find_byte3(b';', b':', b','), Some(3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_byte3(&self, b1: u8, b2: u8, b3: u8) -> Option<usize> {
+		bytes::find_byte3(&self.0, b1, b2, b3)
+	}
+
+	/// Returns the byte offset of the last occurrence of any of `b1`, `b2` or `b3`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"key: value; next");
+	/// assert_eq!(raw.rfind_byte3(b';', b':', b','), Some(10));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_byte3(&self, b1: u8, b2: u8, b3: u8) -> Option<usize> {
+		bytes::rfind_byte3(&self.0, b1, b2, b3)
+	}
+
+	/// Returns the byte offset of the first byte that is in `byteset`.
+	///
+	/// This is the equivalent of C's `strcspn`, except that [`None`] is returned
+	/// when no byte of the set occurs. Sets of up to three bytes use the
+	/// `memchr` routines when the `memchr` feature is enabled.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"token\xFF(arg), rest");
+	/// assert_eq!(raw.find_byteset(b"(),"), Some(6));
+	/// assert_eq!(raw.find_byteset(b"{}"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_byteset<B>(&self, byteset: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		bytes::find_byteset(&self.0, byteset.as_ref())
+	}
+
+	/// Returns the byte offset of the last byte that is in `byteset`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a/b\\c\xFF");
+	/// assert_eq!(raw.rfind_byteset(b"/\\"), Some(3));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_byteset<B>(&self, byteset: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		bytes::rfind_byteset(&self.0, byteset.as_ref())
+	}
+
+	/// Returns the byte offset of the first byte that is not in `byteset`.
+	///
+	/// This is the equivalent of C's `strspn`, except that [`None`] is returned
+	/// when every byte is in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b" \t\tvalue\xFF");
+	/// assert_eq!(raw.find_not_byteset(b" \t"), Some(3));
+	/// assert_eq!(RawStr::new("   ").find_not_byteset(b" "), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_not_byteset<B>(&self, byteset: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		bytes::find_not_byteset(&self.0, byteset.as_ref())
+	}
+
+	/// Returns the byte offset of the last byte that is not in `byteset`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"value\xFF\0\0\0");
+	/// assert_eq!(raw.rfind_not_byteset(b"\0"), Some(5));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind_not_byteset<B>(&self, byteset: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		bytes::rfind_not_byteset(&self.0, byteset.as_ref())
+	}
+}
+
+impl RawString {
+	/// Removes a leading match of `pattern` in place.
+	/// Returns `true` if a match was removed.
+	///
+	/// See [`RawStr::strip_prefix`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\x02payload".as_slice());
+	/// assert!(raw.strip_prefix_in_place(b'\x02'));
+	/// assert!(!raw.strip_prefix_in_place(b'\x02'));
+	/// assert_eq!(raw, RawString::from("payload"));
+	/// ```
+	#[inline]
+	pub fn strip_prefix_in_place<P, M>(&mut self, pattern: P) -> bool
+	where
+		P: RawPattern<M>
+	{
+		match pattern.into_searcher().match_prefix(&self.0) {
+			Some(len) => {
+				self.0.drain(..len);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Removes a trailing match of `pattern` in place.
+	/// Returns `true` if a match was removed.
+	///
+	/// See [`RawStr::strip_suffix`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"line\xFF\r\n".as_slice());
+	/// assert!(raw.strip_suffix_in_place("\r\n"));
+	/// assert_eq!(raw, RawString::from(b"line\xFF".as_slice()));
+	/// ```
+	#[inline]
+	pub fn strip_suffix_in_place<P, M>(&mut self, pattern: P) -> bool
+	where
+		P: RawPattern<M>
+	{
+		match pattern.into_searcher().match_suffix(&self.0) {
+			Some(len) => {
+				self.0.truncate(self.0.len() - len);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+/// Forward search state shared by the iterators over matches of a pattern.
+#[derive(Clone, Debug)]
+pub(crate) struct Searching<'h, S> {
+	pub(crate) haystack: &'h RawStr,
+	pub(crate) searcher: S,
+	position: usize,
+}
+
+impl<'h, S: RawSearcher> Searching<'h, S> {
+	#[inline]
+	pub(crate) fn new(haystack: &'h RawStr, searcher: S) -> Self {
+		Self { haystack, searcher, position: 0 }
+	}
+
+	/// Returns the next match as absolute `(start, end)` offsets.
+	#[inline]
+	pub(crate) fn next_match(&mut self) -> Option<(usize, usize)> {
+		let rest = self.haystack.0.get(self.position..)?;
+		let (start, end) = self.searcher.search(rest)?;
+		let (start, end) = (self.position + start, self.position + end);
+		// step past empty matches so they are only reported once
+		self.position = if start == end { end + 1 } else { end };
+		Some((start, end))
+	}
+}
+
+/// Backward search state shared by the reverse iterators over matches of a pattern.
+#[derive(Clone, Debug)]
+pub(crate) struct SearchingBack<'h, S> {
+	pub(crate) haystack: &'h RawStr,
+	pub(crate) searcher: S,
+	// `None` once an empty match at offset 0 has been reported
+	end: Option<usize>,
+}
+
+impl<'h, S: RawSearcher> SearchingBack<'h, S> {
+	#[inline]
+	pub(crate) fn new(haystack: &'h RawStr, searcher: S) -> Self {
+		Self { haystack, searcher, end: Some(haystack.len()) }
+	}
+
+	/// Returns the previous match as absolute `(start, end)` offsets.
+	#[inline]
+	pub(crate) fn next_match_back(&mut self) -> Option<(usize, usize)> {
+		let rest = &self.haystack.0[..self.end?];
+		let (start, end) = self.searcher.search_back(rest)?;
+		// step before empty matches so they are only reported once
+		self.end = if start == end { start.checked_sub(1) } else { Some(start) };
+		Some((start, end))
+	}
+}
+
+/// An iterator over the byte offsets of the matches of a pattern.
+///
+/// Created by [`RawStr::find_iter`].
+#[derive(Clone, Debug)]
+pub struct FindIter<'h, S>(Searching<'h, S>);
+
+impl<S: RawSearcher> Iterator for FindIter<'_, S> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next_match().map(|(start, _)| start)
+	}
+}
+
+/// An iterator over the matches of a pattern.
+///
+/// Created by [`RawStr::matches`].
+#[derive(Clone, Debug)]
+pub struct Matches<'h, S>(Searching<'h, S>);
+
+impl<'h, S: RawSearcher> Iterator for Matches<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = self.0.next_match()?;
+		Some(RawStr::from_bytes(&self.0.haystack.0[start..end]))
+	}
+}
+
+/// An iterator over the matches of a pattern and their byte offsets.
+///
+/// Created by [`RawStr::match_indices`].
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'h, S>(Searching<'h, S>);
+
+impl<'h, S: RawSearcher> Iterator for MatchIndices<'h, S> {
+	type Item = (usize, &'h RawStr);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = self.0.next_match()?;
+		Some((start, RawStr::from_bytes(&self.0.haystack.0[start..end])))
+	}
+}
+
+/// A reverse iterator over the matches of a pattern.
+///
+/// Created by [`RawStr::rmatches`].
+#[derive(Clone, Debug)]
+pub struct RMatches<'h, S>(SearchingBack<'h, S>);
+
+impl<'h, S: RawSearcher> Iterator for RMatches<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = self.0.next_match_back()?;
+		Some(RawStr::from_bytes(&self.0.haystack.0[start..end]))
+	}
+}
+
+/// A reverse iterator over the matches of a pattern and their byte offsets.
+///
+/// Created by [`RawStr::rmatch_indices`].
+#[derive(Clone, Debug)]
+pub struct RMatchIndices<'h, S>(SearchingBack<'h, S>);
+
+impl<'h, S: RawSearcher> Iterator for RMatchIndices<'h, S> {
+	type Item = (usize, &'h RawStr);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = self.0.next_match_back()?;
+		Some((start, RawStr::from_bytes(&self.0.haystack.0[start..end])))
+	}
+}
@@ -0,0 +1,297 @@
+// rawstring::segment
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{RawStr, utf8};
+
+impl RawStr {
+	/// Returns an iterator over the extended grapheme clusters of the string.
+	///
+	/// Each valid UTF-8 run is segmented on its own, and each maximal invalid subpart
+	/// (as defined by [`Utf8Chunks`](std::str::Utf8Chunks)) is yielded as a separate item,
+	/// just like it is displayed as a single replacement character.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"e\xCC\x81\xFF\xF0\x9F\x87\xAB\xF0\x9F\x87\xB7!");
+	/// let graphemes: Vec<&RawStr> = raw.graphemes().collect();
+	/// assert_eq!(graphemes, [&b"e\xCC\x81"[..], b"\xFF", "🇫🇷".as_bytes(), b"!"]);
+	///
+	/// // the clusters can also be iterated from the back
+	/// let last_two: Vec<&RawStr> = raw.graphemes().rev().take(2).collect();
+	/// assert_eq!(last_two, ["!", "🇫🇷"]);
+	/// ```
+	#[inline]
+	pub fn graphemes(&self) -> Graphemes<'_> {
+		Graphemes { inner: self.grapheme_indices() }
+	}
+
+	/// Returns an iterator over the extended grapheme clusters of the string and their byte offsets.
+	///
+	/// The clusters are the same as with [`graphemes`](Self::graphemes).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\xFF\xFEe\xCC\x81");
+	/// let offsets: Vec<usize> = raw.grapheme_indices().map(|(i, _)| i).collect();
+	/// assert_eq!(offsets, [0, 1, 2, 3]);
+	/// assert_eq!(raw.grapheme_indices().last(), Some((3, RawStr::new("e\u{301}"))));
+	/// ```
+	#[inline]
+	pub fn grapheme_indices(&self) -> GraphemeIndices<'_> {
+		GraphemeIndices { segments: Segments::new(self, |s| s.grapheme_indices(true)) }
+	}
+
+	/// Returns an iterator over the words of the string,
+	/// as delimited by the Unicode word boundaries of [UAX #29](https://www.unicode.org/reports/tr29/).
+	///
+	/// Only the segments containing letters, numbers or ideographs are words, so
+	/// whitespace and punctuation are skipped. Invalid UTF-8 is never part of a word
+	/// and separates the words around it.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"The quick (\"brown\") fox can't\xFFjump 32.3 feet");
+	/// let words: Vec<&RawStr> = raw.words().collect();
+	/// assert_eq!(words, ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet"]);
+	/// ```
+	#[inline]
+	pub fn words(&self) -> Words<'_> {
+		Words { inner: self.word_indices() }
+	}
+
+	/// Returns an iterator over the words of the string and their byte offsets.
+	///
+	/// The words are the same as with [`words`](Self::words).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFFhello, world");
+	/// let words: Vec<(usize, &RawStr)> = raw.word_indices().collect();
+	/// assert_eq!(words, [(1, RawStr::new("hello")), (8, RawStr::new("world"))]);
+	/// ```
+	#[inline]
+	pub fn word_indices(&self) -> WordIndices<'_> {
+		WordIndices { segments: Segments::new(self, |s| s.split_word_bound_indices()) }
+	}
+
+	/// Returns an iterator over the sentences of the string,
+	/// as delimited by the Unicode sentence boundaries of [UAX #29](https://www.unicode.org/reports/tr29/).
+	///
+	/// Sentences keep their trailing whitespace, so together they cover the whole string.
+	/// Invalid UTF-8 ends the sentence before it, and each maximal invalid subpart
+	/// is yielded as a separate item.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"Mr. Fox jumped. The dog was too lazy.\xFF Really?");
+	/// let sentences: Vec<&RawStr> = raw.sentences().collect();
+	/// assert_eq!(sentences, [&b"Mr. "[..], b"Fox jumped. ", b"The dog was too lazy.", b"\xFF", b" Really?"]);
+	/// ```
+	#[inline]
+	pub fn sentences(&self) -> Sentences<'_> {
+		Sentences { segments: Segments::new(self, |s| s.split_sentence_bound_indices()) }
+	}
+}
+
+/// Segments the valid UTF-8 runs of a string with a `str` segmenter,
+/// yielding each maximal invalid subpart as its own segment.
+#[derive(Clone, Debug)]
+struct Segments<'h, I> {
+	// the part of the string that has not been split into runs yet, and its offset
+	rest: &'h [u8],
+	offset: usize,
+	// the segments of the valid runs taken from either end of `rest`, and the offsets of the runs
+	front: Option<(usize, I)>,
+	back: Option<(usize, I)>,
+	segmenter: fn(&'h str) -> I,
+}
+
+impl<'h, I> Segments<'h, I>
+where
+	I: Iterator<Item = (usize, &'h str)>
+{
+	#[inline]
+	fn new(s: &'h RawStr, segmenter: fn(&'h str) -> I) -> Self {
+		Self { rest: &s.0, offset: 0, front: None, back: None, segmenter }
+	}
+
+	/// Returns the next segment and its offset, and whether it is valid UTF-8.
+	fn next_segment(&mut self) -> Option<(usize, &'h RawStr, bool)> {
+		loop {
+			if let Some((start, segments)) = &mut self.front {
+				if let Some((i, segment)) = segments.next() {
+					return Some((*start + i, RawStr::new(segment), true));
+				}
+				self.front = None;
+			}
+
+			let Some(chunk) = self.rest.utf8_chunks().next() else {
+				// only the run taken from the back is left
+				let (start, segments) = self.back.as_mut()?;
+				let (i, segment) = segments.next()?;
+				return Some((*start + i, RawStr::new(segment), true));
+			};
+			let start = self.offset;
+			if chunk.valid().is_empty() {
+				let (invalid, rest) = self.rest.split_at(chunk.invalid().len());
+				self.rest = rest;
+				self.offset += invalid.len();
+				return Some((start, RawStr::from_bytes(invalid), false));
+			}
+			self.rest = &self.rest[chunk.valid().len()..];
+			self.offset += chunk.valid().len();
+			self.front = Some((start, (self.segmenter)(chunk.valid())));
+		}
+	}
+}
+
+impl<'h, I> Segments<'h, I>
+where
+	I: DoubleEndedIterator<Item = (usize, &'h str)>
+{
+	/// Returns the previous segment and its offset, and whether it is valid UTF-8.
+	fn next_segment_back(&mut self) -> Option<(usize, &'h RawStr, bool)> {
+		loop {
+			if let Some((start, segments)) = &mut self.back {
+				if let Some((i, segment)) = segments.next_back() {
+					return Some((*start + i, RawStr::new(segment), true));
+				}
+				self.back = None;
+			}
+
+			let Some((c, len)) = utf8::decode_last(self.rest) else {
+				// only the run taken from the front is left
+				let (start, segments) = self.front.as_mut()?;
+				let (i, segment) = segments.next_back()?;
+				return Some((*start + i, RawStr::new(segment), true));
+			};
+			if c.is_none() {
+				let (rest, invalid) = self.rest.split_at(self.rest.len() - len);
+				self.rest = rest;
+				return Some((self.offset + rest.len(), RawStr::from_bytes(invalid), false));
+			}
+
+			// extend the run backwards up to the previous invalid subpart
+			let mut run_start = self.rest.len() - len;
+			while let Some((Some(_), len)) = utf8::decode_last(&self.rest[..run_start]) {
+				run_start -= len;
+			}
+			let (rest, run) = self.rest.split_at(run_start);
+			self.rest = rest;
+			// SAFETY: the run is made of valid characters only
+			let run = unsafe { std::str::from_utf8_unchecked(run) };
+			self.back = Some((self.offset + run_start, (self.segmenter)(run)));
+		}
+	}
+}
+
+/// An iterator over the extended grapheme clusters of a string.
+///
+/// Created by [`RawStr::graphemes`].
+#[derive(Clone, Debug)]
+pub struct Graphemes<'h> {
+	inner: GraphemeIndices<'h>,
+}
+
+impl<'h> Iterator for Graphemes<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|(_, grapheme)| grapheme)
+	}
+}
+
+impl DoubleEndedIterator for Graphemes<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(|(_, grapheme)| grapheme)
+	}
+}
+
+/// An iterator over the extended grapheme clusters of a string and their byte offsets.
+///
+/// Created by [`RawStr::grapheme_indices`].
+#[derive(Clone, Debug)]
+pub struct GraphemeIndices<'h> {
+	segments: Segments<'h, unicode_segmentation::GraphemeIndices<'h>>,
+}
+
+impl<'h> Iterator for GraphemeIndices<'h> {
+	type Item = (usize, &'h RawStr);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.segments.next_segment().map(|(offset, grapheme, _)| (offset, grapheme))
+	}
+}
+
+impl DoubleEndedIterator for GraphemeIndices<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.segments.next_segment_back().map(|(offset, grapheme, _)| (offset, grapheme))
+	}
+}
+
+/// An iterator over the words of a string.
+///
+/// Created by [`RawStr::words`].
+#[derive(Clone, Debug)]
+pub struct Words<'h> {
+	inner: WordIndices<'h>,
+}
+
+impl<'h> Iterator for Words<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|(_, word)| word)
+	}
+}
+
+/// An iterator over the words of a string and their byte offsets.
+///
+/// Created by [`RawStr::word_indices`].
+#[derive(Clone, Debug)]
+pub struct WordIndices<'h> {
+	segments: Segments<'h, unicode_segmentation::UWordBoundIndices<'h>>,
+}
+
+impl<'h> Iterator for WordIndices<'h> {
+	type Item = (usize, &'h RawStr);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// same as `unicode_words`, which keeps the segments with an alphanumeric character
+		loop {
+			match self.segments.next_segment()? {
+				(offset, word, true) if word.chars().any(char::is_alphanumeric) => return Some((offset, word)),
+				_ => continue,
+			}
+		}
+	}
+}
+
+/// An iterator over the sentences of a string.
+///
+/// Created by [`RawStr::sentences`].
+#[derive(Clone, Debug)]
+pub struct Sentences<'h> {
+	segments: Segments<'h, unicode_segmentation::USentenceBoundIndices<'h>>,
+}
+
+impl<'h> Iterator for Sentences<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.segments.next_segment().map(|(_, sentence, _)| sentence)
+	}
+}
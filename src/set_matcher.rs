@@ -0,0 +1,146 @@
+// rawstring::set_matcher
+
+use aho_corasick::{AhoCorasick, BuildError, MatchKind};
+
+use crate::RawStr;
+
+/// A matcher that searches for many needles at once.
+///
+/// [`RawStrSetMatcher`] is built on the [`aho-corasick`](aho_corasick) crate
+/// and reports leftmost-first matches: at each position the earliest match wins,
+/// and among needles matching at the same position, the one given first wins.
+///
+/// Requires the `aho-corasick` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawStrSetMatcher};
+/// let matcher = RawStrSetMatcher::new([&b"\x7FELF"[..], b"PK\x03\x04", b"%PDF"]).unwrap();
+/// let blob = RawStr::new(b"\x00\x00PK\x03\x04\xFF\xFF%PDF");
+///
+/// assert!(matcher.is_match(blob));
+/// let found = matcher.find(blob).unwrap();
+/// assert_eq!((found.needle(), found.start()), (1, 2));
+///
+/// let needles: Vec<usize> = matcher.find_iter(blob).map(|m| m.needle()).collect();
+/// assert_eq!(needles, [1, 2]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RawStrSetMatcher {
+	inner: AhoCorasick,
+}
+
+impl RawStrSetMatcher {
+	/// Builds a new [`RawStrSetMatcher`] from the given needles.
+	///
+	/// Returns a [`BuildError`] if the automaton would exceed its size limits.
+	#[inline]
+	pub fn new<I, B>(needles: I) -> Result<Self, BuildError>
+	where
+		I: IntoIterator<Item = B>,
+		B: AsRef<[u8]>
+	{
+		let inner = AhoCorasick::builder()
+			.match_kind(MatchKind::LeftmostFirst)
+			.build(needles)?;
+		Ok(Self { inner })
+	}
+
+	/// Returns the number of needles this matcher searches for.
+	#[inline]
+	#[must_use]
+	pub fn needles_len(&self) -> usize {
+		self.inner.patterns_len()
+	}
+
+	/// Returns `true` if any of the needles occur in `haystack`.
+	#[inline]
+	#[must_use]
+	pub fn is_match(&self, haystack: &RawStr) -> bool {
+		self.inner.is_match(&haystack.0)
+	}
+
+	/// Returns the first match in `haystack`, if any.
+	#[inline]
+	#[must_use]
+	pub fn find<'h>(&self, haystack: &'h RawStr) -> Option<SetMatch<'h>> {
+		let m = self.inner.find(&haystack.0)?;
+		Some(SetMatch::new(haystack, m))
+	}
+
+	/// Returns an iterator over all non-overlapping matches in `haystack`.
+	#[inline]
+	pub fn find_iter<'a, 'h>(&'a self, haystack: &'h RawStr) -> SetMatches<'a, 'h> {
+		SetMatches {
+			haystack,
+			inner: self.inner.find_iter(&haystack.0),
+		}
+	}
+}
+
+/// A single match reported by a [`RawStrSetMatcher`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SetMatch<'h> {
+	needle: usize,
+	start: usize,
+	matched: &'h RawStr,
+}
+
+impl<'h> SetMatch<'h> {
+	#[inline]
+	fn new(haystack: &'h RawStr, m: aho_corasick::Match) -> Self {
+		Self {
+			needle: m.pattern().as_usize(),
+			start: m.start(),
+			matched: RawStr::from_bytes(&haystack.0[m.range()]),
+		}
+	}
+
+	/// Returns the index of the needle that matched,
+	/// in the order the needles were given to [`RawStrSetMatcher::new`].
+	#[inline]
+	#[must_use]
+	pub fn needle(&self) -> usize {
+		self.needle
+	}
+
+	/// Returns the byte offset at which the match starts.
+	#[inline]
+	#[must_use]
+	pub fn start(&self) -> usize {
+		self.start
+	}
+
+	/// Returns the byte offset at which the match ends.
+	#[inline]
+	#[must_use]
+	pub fn end(&self) -> usize {
+		self.start + self.matched.len()
+	}
+
+	/// Returns the matched bytes.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &'h RawStr {
+		self.matched
+	}
+}
+
+/// An iterator over the matches of a [`RawStrSetMatcher`].
+///
+/// Created by [`RawStrSetMatcher::find_iter`].
+#[derive(Debug)]
+pub struct SetMatches<'a, 'h> {
+	haystack: &'h RawStr,
+	inner: aho_corasick::FindIter<'a, 'h>,
+}
+
+impl<'h> Iterator for SetMatches<'_, 'h> {
+	type Item = SetMatch<'h>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let m = self.inner.next()?;
+		Some(SetMatch::new(self.haystack, m))
+	}
+}
@@ -0,0 +1,141 @@
+// rawstring::shell
+
+use crate::{RawStr, RawString, ShellSplitError};
+
+impl RawStr {
+	/// Quotes the string for a POSIX shell, so that it is read back as a single word
+	/// with exactly these bytes.
+	///
+	/// The string is returned unchanged if it is not empty and made only of bytes
+	/// that are never special to the shell: ASCII letters and digits and `_@%+=:,./-`.
+	/// Otherwise it is wrapped in single quotes, inside of which every byte is literal,
+	/// and each single quote is written as `'\''`.
+	///
+	/// Note that a shell word cannot contain a NUL byte, so one can never be passed through.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("/tmp/out.log").shell_quote().as_ref(), "/tmp/out.log");
+	/// assert_eq!(RawStr::new("").shell_quote().as_ref(), "''");
+	/// assert_eq!(RawStr::new(b"it's $HOME\xFF").shell_quote().as_ref(), b"'it'\\''s $HOME\xFF'");
+	/// ```
+	#[must_use]
+	pub fn shell_quote(&self) -> RawString {
+		let safe = |b: u8| b.is_ascii_alphanumeric() || b"_@%+=:,./-".contains(&b);
+		if !self.is_empty() && self.0.iter().all(|&b| safe(b)) {
+			return RawString(self.0.to_vec());
+		}
+
+		let mut out = Vec::with_capacity(self.len() + 2);
+		out.push(b'\'');
+		for &b in &self.0 {
+			if b == b'\'' {
+				out.extend_from_slice(b"'\\''");
+			} else {
+				out.push(b);
+			}
+		}
+		out.push(b'\'');
+		RawString(out)
+	}
+
+	/// Splits a command line into words following the quoting rules of a POSIX shell.
+	///
+	/// Words are separated by unquoted spaces, tabs and newlines.
+	/// Inside single quotes every byte is literal.
+	/// Inside double quotes a backslash only escapes `$`, `` ` ``, `"`, `\` and newline,
+	/// and is kept before any other byte.
+	/// Outside of quotes a backslash escapes any byte.
+	/// In both cases, an escaped newline is a line continuation and is removed.
+	///
+	/// Only quoting is handled: no expansion of any kind is performed,
+	/// and `#`, `;`, `|` and the other operators are ordinary bytes.
+	///
+	/// # Errors
+	/// Returns a [`ShellSplitError`] if a quote is not closed, or if the string ends with a backslash.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString, ShellSplitError};
+	/// let line = RawStr::new(b"grep -e 'a b' \"x\\\"y\" c\\ d \xFF''");
+	/// let words = line.shell_split().unwrap();
+	/// assert_eq!(words, [&b"grep"[..], b"-e", b"a b", b"x\"y", b"c d", b"\xFF"].map(RawString::from));
+	///
+	/// // a line continuation between words is removed entirely
+	/// assert_eq!(RawStr::new("a \\\n b \\\n").shell_split().unwrap(), ["a", "b"].map(RawString::from));
+	///
+	/// assert_eq!(RawStr::new("echo 'oops").shell_split(), Err(ShellSplitError::UnterminatedQuote { index: 5 }));
+	///
+	/// // quoting and splitting round-trip
+	/// let args = [&b"it's"[..], b"", b"a\tb\xFF"];
+	/// let line: Vec<u8> = args.iter().flat_map(|arg| [&RawStr::new(arg).shell_quote().0[..], b" "].concat()).collect();
+	/// assert_eq!(RawStr::new(&line).shell_split().unwrap(), args.map(RawString::from));
+	/// ```
+	pub fn shell_split(&self) -> Result<Vec<RawString>, ShellSplitError> {
+		let bytes = &self.0;
+		let mut words = Vec::new();
+		// the word being built, if one has started
+		let mut word: Option<Vec<u8>> = None;
+		let mut i = 0;
+		while let Some(&b) = bytes.get(i) {
+			match b {
+				b' ' | b'\t' | b'\n' => {
+					words.extend(word.take().map(RawString));
+					i += 1;
+				}
+				b'\\' => {
+					let &escaped = bytes.get(i + 1).ok_or(ShellSplitError::TrailingBackslash { index: i })?;
+					// a line continuation is removed without starting a word
+					if escaped != b'\n' {
+						word.get_or_insert_default().push(escaped);
+					}
+					i += 2;
+				}
+				b'\'' => {
+					let word = word.get_or_insert_default();
+					let len = bytes[i + 1..]
+						.iter()
+						.position(|&b| b == b'\'')
+						.ok_or(ShellSplitError::UnterminatedQuote { index: i })?;
+					word.extend_from_slice(&bytes[i + 1..i + 1 + len]);
+					i += len + 2;
+				}
+				b'"' => {
+					let word = word.get_or_insert_default();
+					let start = i;
+					i += 1;
+					loop {
+						match bytes.get(i) {
+							None => return Err(ShellSplitError::UnterminatedQuote { index: start }),
+							Some(b'"') => break,
+							Some(b'\\') => match bytes.get(i + 1) {
+								None => return Err(ShellSplitError::UnterminatedQuote { index: start }),
+								Some(b'\n') => i += 2,
+								Some(&escaped @ (b'$' | b'`' | b'"' | b'\\')) => {
+									word.push(escaped);
+									i += 2;
+								}
+								Some(_) => {
+									word.push(b'\\');
+									i += 1;
+								}
+							},
+							Some(&b) => {
+								word.push(b);
+								i += 1;
+							}
+						}
+					}
+					i += 1;
+				}
+				_ => {
+					word.get_or_insert_default().push(b);
+					i += 1;
+				}
+			}
+		}
+		words.extend(word.map(RawString));
+		Ok(words)
+	}
+}
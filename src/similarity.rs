@@ -0,0 +1,166 @@
+// rawstring::similarity
+
+//! Edit distances between raw strings.
+//!
+//! All distances are computed over bytes, so the inputs do not need to be valid UTF-8.
+//! A multibyte character therefore counts as several edits.
+//!
+//! # Examples
+//! ```
+//! use rawstring::{RawStr, similarity};
+//!
+//! let typed = RawStr::new(b"cnofig\xFF");
+//! let known = RawStr::new(b"config\xFF");
+//! assert_eq!(similarity::levenshtein(typed, known), 2);
+//! assert_eq!(similarity::damerau_levenshtein(typed, known), 1);
+//! assert!(similarity::is_within_distance(typed, known, 2));
+//! ```
+
+/// Returns the Levenshtein distance between `a` and `b`:
+/// the minimum number of single-byte insertions, deletions and substitutions
+/// needed to turn one into the other.
+///
+/// # Examples
+/// ```
+/// # use rawstring::similarity::levenshtein;
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein(b"\xFF", b""), 1);
+/// ```
+#[must_use]
+pub fn levenshtein<A, B>(a: &A, b: &B) -> usize
+where
+	A: ?Sized + AsRef<[u8]>,
+	B: ?Sized + AsRef<[u8]>
+{
+	let (a, b) = (a.as_ref(), b.as_ref());
+	// keep the row as short as possible
+	let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+	let mut row: Vec<usize> = (0..=b.len()).collect();
+	for (i, &x) in a.iter().enumerate() {
+		let mut diagonal = row[0];
+		row[0] = i + 1;
+		for (j, &y) in b.iter().enumerate() {
+			let substitution = diagonal + usize::from(x != y);
+			diagonal = row[j + 1];
+			row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+		}
+	}
+	row[b.len()]
+}
+
+/// Returns the Damerau-Levenshtein distance between `a` and `b`.
+///
+/// This is the [Levenshtein distance](levenshtein) with transpositions of
+/// two bytes counting as a single edit, even when other edits happen in between.
+///
+/// # Examples
+/// ```
+/// # use rawstring::similarity::damerau_levenshtein;
+/// assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+/// assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+/// ```
+#[must_use]
+pub fn damerau_levenshtein<A, B>(a: &A, b: &B) -> usize
+where
+	A: ?Sized + AsRef<[u8]>,
+	B: ?Sized + AsRef<[u8]>
+{
+	let (a, b) = (a.as_ref(), b.as_ref());
+	let (n, m) = (a.len(), b.len());
+	let max = n + m;
+	let width = m + 2;
+
+	// d[(i + 1) * width + (j + 1)] is the distance between a[..i] and b[..j],
+	// with an extra row and column of `max` acting as sentinels
+	let mut d = vec![0; (n + 2) * width];
+	d[0] = max;
+	for i in 0..=n {
+		d[(i + 1) * width] = max;
+		d[(i + 1) * width + 1] = i;
+	}
+	for j in 0..=m {
+		d[j + 1] = max;
+		d[width + j + 1] = j;
+	}
+
+	// the last row in which each byte was seen in `a`
+	let mut last_row = [0; 256];
+	for i in 1..=n {
+		// the last column in this row where a[i - 1] matched
+		let mut last_match_col = 0;
+		for j in 1..=m {
+			let k = last_row[usize::from(b[j - 1])];
+			let l = last_match_col;
+			let cost = if a[i - 1] == b[j - 1] {
+				last_match_col = j;
+				0
+			} else {
+				1
+			};
+			d[(i + 1) * width + j + 1] = (d[i * width + j] + cost)
+				.min(d[(i + 1) * width + j] + 1)
+				.min(d[i * width + j + 1] + 1)
+				.min(d[k * width + l] + (i - k - 1) + 1 + (j - l - 1));
+		}
+		last_row[usize::from(a[i - 1])] = i;
+	}
+	d[(n + 1) * width + m + 1]
+}
+
+/// Returns `true` if the [Levenshtein distance](levenshtein) between `a` and `b`
+/// is at most `max_distance`.
+///
+/// This only computes the band of the distance matrix that can stay within
+/// `max_distance` and stops as soon as the distance is known to exceed it,
+/// making it much faster than [`levenshtein`] for small bounds.
+///
+/// # Examples
+/// ```
+/// # use rawstring::similarity::is_within_distance;
+/// assert!(is_within_distance("kitten", "sitting", 3));
+/// assert!(!is_within_distance("kitten", "sitting", 2));
+/// assert!(!is_within_distance("a", "a very long string", 4));
+/// ```
+#[must_use]
+pub fn is_within_distance<A, B>(a: &A, b: &B, max_distance: usize) -> bool
+where
+	A: ?Sized + AsRef<[u8]>,
+	B: ?Sized + AsRef<[u8]>
+{
+	let (a, b) = (a.as_ref(), b.as_ref());
+	let (n, m) = (a.len(), b.len());
+	if n.abs_diff(m) > max_distance {
+		return false;
+	}
+
+	let k = max_distance;
+	// distances above `k` are all equivalent, so cap them to avoid overflow
+	let over = k + 1;
+	let mut prev: Vec<usize> = (0..=m).map(|j| j.min(over)).collect();
+	let mut cur = vec![over; m + 1];
+
+	for i in 1..=n {
+		let lo = i.saturating_sub(k).max(1);
+		let hi = (i + k).min(m);
+
+		cur[lo - 1] = if lo == 1 { i.min(over) } else { over };
+		let mut row_min = cur[lo - 1];
+		for j in lo..=hi {
+			let substitution = prev[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+			let value = substitution.min(prev[j] + 1).min(cur[j - 1] + 1).min(over);
+			cur[j] = value;
+			row_min = row_min.min(value);
+		}
+		// the next row reads one column past this band
+		if hi < m {
+			cur[hi + 1] = over;
+		}
+
+		if row_min > k {
+			return false;
+		}
+		std::mem::swap(&mut prev, &mut cur);
+	}
+	prev[m] <= k
+}
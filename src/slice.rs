@@ -0,0 +1,225 @@
+// rawstring::slice
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Returns an iterator over `chunk_size` bytes of the string at a time, starting at the beginning.
+	///
+	/// This is [`<[u8]>::chunks`](slice::chunks), yielding [`RawStr`]s.
+	/// The last chunk is shorter if the length is not a multiple of `chunk_size`.
+	///
+	/// # Panics
+	/// Panics if `chunk_size` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"abc\xFFde");
+	/// let chunks: Vec<&RawStr> = raw.chunks(4).collect();
+	/// assert_eq!(chunks, [&b"abc\xFF"[..], b"de"]);
+	/// ```
+	#[inline]
+	pub fn chunks(&self, chunk_size: usize) -> Chunks<'_> {
+		Chunks { inner: self.0.chunks(chunk_size) }
+	}
+
+	/// Returns an iterator over all contiguous windows of `size` bytes of the string.
+	///
+	/// This is [`<[u8]>::windows`](slice::windows), yielding [`RawStr`]s.
+	/// There are no windows if the string is shorter than `size`.
+	///
+	/// # Panics
+	/// Panics if `size` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"ab\xFF");
+	/// let windows: Vec<&RawStr> = raw.windows(2).collect();
+	/// assert_eq!(windows, [&b"ab"[..], b"b\xFF"]);
+	/// assert_eq!(raw.windows(4).count(), 0);
+	/// ```
+	#[inline]
+	pub fn windows(&self, size: usize) -> Windows<'_> {
+		Windows { inner: self.0.windows(size) }
+	}
+
+	/// Divides the string into two at byte offset `mid`.
+	///
+	/// # Panics
+	/// Panics if `mid > len`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let (magic, rest) = RawStr::new(b"\x7FELF\x02\x01").split_at(4);
+	/// assert_eq!(magic, b"\x7FELF");
+	/// assert_eq!(rest, b"\x02\x01");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn split_at(&self, mid: usize) -> (&RawStr, &RawStr) {
+		let (a, b) = self.0.split_at(mid);
+		(RawStr::from_bytes(a), RawStr::from_bytes(b))
+	}
+
+	/// Divides the string into two at byte offset `mid`,
+	/// or returns [`None`] if `mid > len`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"ab\xFF");
+	/// assert_eq!(raw.split_at_checked(2), Some((RawStr::new("ab"), RawStr::new(b"\xFF"))));
+	/// assert_eq!(raw.split_at_checked(4), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn split_at_checked(&self, mid: usize) -> Option<(&RawStr, &RawStr)> {
+		let (a, b) = self.0.split_at_checked(mid)?;
+		Some((RawStr::from_bytes(a), RawStr::from_bytes(b)))
+	}
+
+	/// Divides the string into two mutable halves at byte offset `mid`.
+	///
+	/// # Panics
+	/// Panics if `mid > len`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from("key=value");
+	/// let (key, value) = raw.as_mut().split_at_mut(3);
+	/// key.make_ascii_uppercase();
+	/// value[0] = b':';
+	/// assert_eq!(raw.as_ref(), "KEY:value");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn split_at_mut(&mut self, mid: usize) -> (&mut RawStr, &mut RawStr) {
+		let (a, b) = self.0.split_at_mut(mid);
+		(RawStr::from_bytes_mut(a), RawStr::from_bytes_mut(b))
+	}
+
+	/// Divides the string into two mutable halves at byte offset `mid`,
+	/// or returns [`None`] if `mid > len`.
+	#[inline]
+	#[must_use]
+	pub fn split_at_mut_checked(&mut self, mid: usize) -> Option<(&mut RawStr, &mut RawStr)> {
+		let (a, b) = self.0.split_at_mut_checked(mid)?;
+		Some((RawStr::from_bytes_mut(a), RawStr::from_bytes_mut(b)))
+	}
+
+	/// Returns the first byte and the rest of the string, or [`None`] if it is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"+\xFF1").split_first(), Some((b'+', RawStr::new(b"\xFF1"))));
+	/// assert_eq!(RawStr::new("").split_first(), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn split_first(&self) -> Option<(u8, &RawStr)> {
+		let (&first, rest) = self.0.split_first()?;
+		Some((first, RawStr::from_bytes(rest)))
+	}
+
+	/// Returns the last byte and the rest of the string, or [`None`] if it is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xFFline\n").split_last(), Some((b'\n', RawStr::new(b"\xFFline"))));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn split_last(&self) -> Option<(u8, &RawStr)> {
+		let (&last, rest) = self.0.split_last()?;
+		Some((last, RawStr::from_bytes(rest)))
+	}
+
+	/// Returns a mutable reference to the first byte and the rest of the string,
+	/// or [`None`] if it is empty.
+	#[inline]
+	#[must_use]
+	pub fn split_first_mut(&mut self) -> Option<(&mut u8, &mut RawStr)> {
+		let (first, rest) = self.0.split_first_mut()?;
+		Some((first, RawStr::from_bytes_mut(rest)))
+	}
+
+	/// Returns a mutable reference to the last byte and the rest of the string,
+	/// or [`None`] if it is empty.
+	#[inline]
+	#[must_use]
+	pub fn split_last_mut(&mut self) -> Option<(&mut u8, &mut RawStr)> {
+		let (last, rest) = self.0.split_last_mut()?;
+		Some((last, RawStr::from_bytes_mut(rest)))
+	}
+}
+
+/// An iterator over a string in chunks of bytes.
+///
+/// Created by [`RawStr::chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks<'h> {
+	inner: std::slice::Chunks<'h, u8>,
+}
+
+impl<'h> Iterator for Chunks<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(RawStr::from_bytes)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for Chunks<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(RawStr::from_bytes)
+	}
+}
+
+impl ExactSizeIterator for Chunks<'_> {}
+
+impl std::iter::FusedIterator for Chunks<'_> {}
+
+/// An iterator over the overlapping windows of bytes of a string.
+///
+/// Created by [`RawStr::windows`].
+#[derive(Clone, Debug)]
+pub struct Windows<'h> {
+	inner: std::slice::Windows<'h, u8>,
+}
+
+impl<'h> Iterator for Windows<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(RawStr::from_bytes)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for Windows<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(RawStr::from_bytes)
+	}
+}
+
+impl ExactSizeIterator for Windows<'_> {}
+
+impl std::iter::FusedIterator for Windows<'_> {}
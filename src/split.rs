@@ -0,0 +1,493 @@
+// rawstring::split
+
+use crate::{
+	RawStr,
+	pattern::{BytePredicateSearcher, CharPredicateSearcher, RawPattern, RawSearcher},
+	search::{Searching, SearchingBack},
+};
+
+impl RawStr {
+	/// Returns an iterator over the pieces of the string separated by matches of `pattern`.
+	///
+	/// See [`RawPattern`] for the kinds of patterns accepted.
+	/// As with [`str::split`], a match at either end produces an empty piece.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a::\xFF::::b");
+	/// let parts: Vec<&RawStr> = raw.split("::").collect();
+	/// assert_eq!(parts, [&b"a"[..], b"\xFF", b"", b"b"]);
+	///
+	/// let fields: Vec<&RawStr> = RawStr::new("1, 2,3").split(&[',', ' ']).collect();
+	/// assert_eq!(fields, ["1", "", "2", "3"]);
+	/// ```
+	#[inline]
+	pub fn split<P, M>(&self, pattern: P) -> Split<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		Split {
+			matches: Searching::new(self, pattern.into_searcher()),
+			start: 0,
+			finished: false,
+		}
+	}
+
+	/// Returns an iterator over the pieces of the string separated by matches of `pattern`,
+	/// starting from the end of the string.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"usr/\xFF/bin");
+	/// let parts: Vec<&RawStr> = raw.rsplit(b'/').collect();
+	/// assert_eq!(parts, [&b"bin"[..], b"\xFF", b"usr"]);
+	/// ```
+	#[inline]
+	pub fn rsplit<P, M>(&self, pattern: P) -> RSplit<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		RSplit {
+			matches: SearchingBack::new(self, pattern.into_searcher()),
+			end: self.len(),
+			finished: false,
+		}
+	}
+
+	/// Returns an iterator over at most `n` pieces of the string separated by matches of `pattern`.
+	///
+	/// The last piece holds the rest of the string, which is not searched any further.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"key=value=\xFF");
+	/// let parts: Vec<&RawStr> = raw.splitn(2, b'=').collect();
+	/// assert_eq!(parts, [&b"key"[..], b"value=\xFF"]);
+	/// assert_eq!(raw.splitn(0, b'=').count(), 0);
+	/// ```
+	#[inline]
+	pub fn splitn<P, M>(&self, n: usize, pattern: P) -> SplitN<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		SplitN { split: self.split(pattern), count: n }
+	}
+
+	/// Returns an iterator over at most `n` pieces of the string separated by matches of `pattern`,
+	/// starting from the end of the string.
+	///
+	/// The last piece holds the rest of the string, which is not searched any further.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"[::1]:\xFF:8080");
+	/// let parts: Vec<&RawStr> = raw.rsplitn(2, b':').collect();
+	/// assert_eq!(parts, [&b"8080"[..], b"[::1]:\xFF"]);
+	/// ```
+	#[inline]
+	pub fn rsplitn<P, M>(&self, n: usize, pattern: P) -> RSplitN<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		RSplitN { split: self.rsplit(pattern), count: n }
+	}
+
+	/// Returns an iterator over the pieces of the string separated by matches of `pattern`,
+	/// with each match kept at the end of the piece before it.
+	///
+	/// As with [`str::split_inclusive`], a match at the end of the string
+	/// does not produce an empty last piece.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let log = RawStr::new(b"ok\n\xFF bad\r\n\nlast");
+	/// let lines: Vec<&RawStr> = log.split_inclusive(b'\n').collect();
+	/// assert_eq!(lines, [&b"ok\n"[..], b"\xFF bad\r\n", b"\n", b"last"]);
+	/// assert_eq!(lines.iter().map(|l| l.len()).sum::<usize>(), log.len());
+	///
+	/// assert_eq!(RawStr::new("a\n").split_inclusive(b'\n').count(), 1);
+	/// ```
+	#[inline]
+	pub fn split_inclusive<P, M>(&self, pattern: P) -> SplitInclusive<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		SplitInclusive { split: self.split(pattern) }
+	}
+
+	/// Returns an iterator over the pieces of the string terminated by matches of `pattern`.
+	///
+	/// This is [`split`](Self::split) without the empty last piece
+	/// produced when the string ends with a match.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let records = RawStr::new(b"a\0\xFF\0\0");
+	/// let parts: Vec<&RawStr> = records.split_terminator(b'\0').collect();
+	/// assert_eq!(parts, [&b"a"[..], b"\xFF", b""]);
+	/// assert_eq!(RawStr::new("").split_terminator(b'\0').count(), 0);
+	/// ```
+	#[inline]
+	pub fn split_terminator<P, M>(&self, pattern: P) -> SplitTerminator<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		SplitTerminator { split: self.split(pattern) }
+	}
+
+	/// Returns an iterator over the pieces of the string terminated by matches of `pattern`,
+	/// starting from the end of the string.
+	///
+	/// This is [`rsplit`](Self::rsplit) without the empty first piece
+	/// produced when the string ends with a match.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let records = RawStr::new(b"a\n\xFF\n");
+	/// let parts: Vec<&RawStr> = records.rsplit_terminator(b'\n').collect();
+	/// assert_eq!(parts, [&b"\xFF"[..], b"a"]);
+	/// ```
+	#[inline]
+	pub fn rsplit_terminator<P, M>(&self, pattern: P) -> RSplitTerminator<'_, P::Searcher>
+	where
+		P: RawPattern<M>
+	{
+		RSplitTerminator { split: self.rsplit(pattern), started: false }
+	}
+
+	/// Returns an iterator over the non-empty pieces of the string separated by Unicode whitespace.
+	///
+	/// Valid UTF-8 sequences are decoded and checked with [`char::is_whitespace`].
+	/// Invalid bytes are never whitespace, so they stay inside the pieces.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let row = RawStr::new(b"  1234 \xFF\xFEproc\xE3\x80\x80S\t\n");
+	/// let fields: Vec<&RawStr> = row.split_whitespace().collect();
+	/// assert_eq!(fields, [&b"1234"[..], b"\xFF\xFEproc", b"S"]);
+	/// ```
+	#[inline]
+	pub fn split_whitespace(&self) -> SplitWhitespace<'_> {
+		SplitWhitespace { split: self.split(char::is_whitespace as IsWhitespace) }
+	}
+
+	/// Returns an iterator over the non-empty pieces of the string separated by ASCII whitespace.
+	///
+	/// Whitespace is as defined by [`u8::is_ascii_whitespace`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let row = RawStr::new(b"root   1 \xFF\t/sbin/init\n");
+	/// let fields: Vec<&RawStr> = row.split_ascii_whitespace().collect();
+	/// assert_eq!(fields, [&b"root"[..], b"1", b"\xFF", b"/sbin/init"]);
+	/// ```
+	#[inline]
+	pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
+		let is_whitespace: IsAsciiWhitespace = |b| b.is_ascii_whitespace();
+		SplitAsciiWhitespace { split: self.split(is_whitespace) }
+	}
+
+	/// Splits the string around the first match of `pattern`,
+	/// returning the pieces before and after it.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"host:\xFF:port");
+	/// assert_eq!(raw.split_once(b':'), Some((RawStr::new("host"), RawStr::new(b"\xFF:port"))));
+	/// assert_eq!(raw.split_once(b'='), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn split_once<P, M>(&self, pattern: P) -> Option<(&RawStr, &RawStr)>
+	where
+		P: RawPattern<M>
+	{
+		let (start, end) = pattern.into_searcher().search(&self.0)?;
+		Some((RawStr::from_bytes(&self.0[..start]), RawStr::from_bytes(&self.0[end..])))
+	}
+
+	/// Splits the string around the last match of `pattern`,
+	/// returning the pieces before and after it.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"host:\xFF:port");
+	/// assert_eq!(raw.rsplit_once(b':'), Some((RawStr::new(b"host:\xFF"), RawStr::new("port"))));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rsplit_once<P, M>(&self, pattern: P) -> Option<(&RawStr, &RawStr)>
+	where
+		P: RawPattern<M>
+	{
+		let (start, end) = pattern.into_searcher().search_back(&self.0)?;
+		Some((RawStr::from_bytes(&self.0[..start]), RawStr::from_bytes(&self.0[end..])))
+	}
+}
+
+/// An iterator over the pieces of a string separated by a pattern.
+///
+/// Created by [`RawStr::split`].
+#[derive(Clone, Debug)]
+pub struct Split<'h, S> {
+	matches: Searching<'h, S>,
+	start: usize,
+	finished: bool,
+}
+
+impl<'h, S: RawSearcher> Iterator for Split<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+		let haystack = self.matches.haystack;
+		match self.matches.next_match() {
+			Some((start, end)) => {
+				let piece = &haystack.0[self.start..start];
+				self.start = end;
+				Some(RawStr::from_bytes(piece))
+			}
+			None => self.remainder(),
+		}
+	}
+}
+
+impl<'h, S> Split<'h, S> {
+	/// Returns the rest of the string without searching it, finishing the iterator.
+	#[inline]
+	fn remainder(&mut self) -> Option<&'h RawStr> {
+		if self.finished {
+			return None;
+		}
+		self.finished = true;
+		Some(RawStr::from_bytes(&self.matches.haystack.0[self.start..]))
+	}
+}
+
+/// An iterator over the pieces of a string separated by a pattern,
+/// with each match kept at the end of the piece before it.
+///
+/// Created by [`RawStr::split_inclusive`].
+#[derive(Clone, Debug)]
+pub struct SplitInclusive<'h, S> {
+	split: Split<'h, S>,
+}
+
+impl<'h, S: RawSearcher> Iterator for SplitInclusive<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let split = &mut self.split;
+		if split.finished {
+			return None;
+		}
+		let haystack = split.matches.haystack;
+		match split.matches.next_match() {
+			Some((_, end)) => {
+				let piece = &haystack.0[split.start..end];
+				split.start = end;
+				Some(RawStr::from_bytes(piece))
+			}
+			None => {
+				split.finished = true;
+				let rest = &haystack.0[split.start..];
+				(!rest.is_empty()).then(|| RawStr::from_bytes(rest))
+			}
+		}
+	}
+}
+
+/// An iterator over the pieces of a string separated by a pattern, from the end.
+///
+/// Created by [`RawStr::rsplit`].
+#[derive(Clone, Debug)]
+pub struct RSplit<'h, S> {
+	matches: SearchingBack<'h, S>,
+	end: usize,
+	finished: bool,
+}
+
+impl<'h, S: RawSearcher> Iterator for RSplit<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+		let haystack = self.matches.haystack;
+		match self.matches.next_match_back() {
+			Some((start, end)) => {
+				let piece = &haystack.0[end..self.end];
+				self.end = start;
+				Some(RawStr::from_bytes(piece))
+			}
+			None => self.remainder(),
+		}
+	}
+}
+
+impl<'h, S> RSplit<'h, S> {
+	/// Returns the rest of the string without searching it, finishing the iterator.
+	#[inline]
+	fn remainder(&mut self) -> Option<&'h RawStr> {
+		if self.finished {
+			return None;
+		}
+		self.finished = true;
+		Some(RawStr::from_bytes(&self.matches.haystack.0[..self.end]))
+	}
+}
+
+/// An iterator over the pieces of a string terminated by a pattern.
+///
+/// Created by [`RawStr::split_terminator`].
+#[derive(Clone, Debug)]
+pub struct SplitTerminator<'h, S> {
+	split: Split<'h, S>,
+}
+
+impl<'h, S: RawSearcher> Iterator for SplitTerminator<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let piece = self.split.next()?;
+		// the last piece is only dropped if it is empty
+		(!self.split.finished || !piece.is_empty()).then_some(piece)
+	}
+}
+
+/// An iterator over the pieces of a string terminated by a pattern, from the end.
+///
+/// Created by [`RawStr::rsplit_terminator`].
+#[derive(Clone, Debug)]
+pub struct RSplitTerminator<'h, S> {
+	split: RSplit<'h, S>,
+	started: bool,
+}
+
+impl<'h, S: RawSearcher> Iterator for RSplitTerminator<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let piece = self.split.next()?;
+		// the first piece is only dropped if it is empty
+		if !self.started {
+			self.started = true;
+			if piece.is_empty() {
+				return self.split.next();
+			}
+		}
+		Some(piece)
+	}
+}
+
+type IsWhitespace = fn(char) -> bool;
+type IsAsciiWhitespace = fn(u8) -> bool;
+
+/// An iterator over the non-empty pieces of a string separated by Unicode whitespace.
+///
+/// Created by [`RawStr::split_whitespace`].
+#[derive(Clone, Debug)]
+pub struct SplitWhitespace<'h> {
+	split: Split<'h, CharPredicateSearcher<IsWhitespace>>,
+}
+
+impl<'h> Iterator for SplitWhitespace<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.split.by_ref().find(|piece| !piece.is_empty())
+	}
+}
+
+/// An iterator over the non-empty pieces of a string separated by ASCII whitespace.
+///
+/// Created by [`RawStr::split_ascii_whitespace`].
+#[derive(Clone, Debug)]
+pub struct SplitAsciiWhitespace<'h> {
+	split: Split<'h, BytePredicateSearcher<IsAsciiWhitespace>>,
+}
+
+impl<'h> Iterator for SplitAsciiWhitespace<'h> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.split.by_ref().find(|piece| !piece.is_empty())
+	}
+}
+
+/// An iterator over a limited number of pieces of a string separated by a pattern.
+///
+/// Created by [`RawStr::splitn`].
+#[derive(Clone, Debug)]
+pub struct SplitN<'h, S> {
+	split: Split<'h, S>,
+	count: usize,
+}
+
+impl<'h, S: RawSearcher> Iterator for SplitN<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.split.remainder()
+			}
+			_ => {
+				self.count -= 1;
+				self.split.next()
+			}
+		}
+	}
+}
+
+/// An iterator over a limited number of pieces of a string separated by a pattern, from the end.
+///
+/// Created by [`RawStr::rsplitn`].
+#[derive(Clone, Debug)]
+pub struct RSplitN<'h, S> {
+	split: RSplit<'h, S>,
+	count: usize,
+}
+
+impl<'h, S: RawSearcher> Iterator for RSplitN<'h, S> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.split.remainder()
+			}
+			_ => {
+				self.count -= 1;
+				self.split.next()
+			}
+		}
+	}
+}
@@ -0,0 +1,186 @@
+// rawstring::terminal
+
+use std::{borrow::Cow, fmt};
+
+use crate::{RawStr, RawString, bytes};
+
+const ESC: u8 = 0x1B;
+const BEL: u8 = 0x07;
+
+impl RawStr {
+	/// Returns a copy of the string with all ANSI escape sequences removed.
+	///
+	/// See [`strip_ansi_escapes_cow`](Self::strip_ansi_escapes_cow) for the sequences recognized.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let output = RawStr::new(b"\x1B[1;31merror:\x1B[0m bad byte \xFF");
+	/// assert_eq!(output.strip_ansi_escapes().as_ref(), b"error: bad byte \xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn strip_ansi_escapes(&self) -> RawString {
+		self.strip_ansi_escapes_cow().into_owned()
+	}
+
+	/// Removes all ANSI escape sequences from the string,
+	/// borrowing it unchanged if it contains none.
+	///
+	/// The following sequences are removed, all introduced by `ESC` (`\x1B`):
+	/// - control sequences (CSI, `ESC [`), up to and including their final byte,
+	/// - operating system commands (OSC, `ESC ]`) and the other control strings
+	///   (`ESC P`, `ESC X`, `ESC ^`, `ESC _`), up to and including the terminating `BEL` or `ESC \`,
+	/// - all other escape sequences, made of intermediate bytes and a final byte.
+	///
+	/// A sequence cut off by the end of the string is removed up to the end.
+	/// A malformed sequence is removed up to the first byte that cannot be part of it,
+	/// which is kept. The 8-bit C1 forms are not recognized,
+	/// as their bytes are continuation bytes in UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use std::{borrow::Cow, fmt};
+	///
+	/// // a hyperlink: OSC 8 terminated by ST, then by BEL
+	/// let link = RawStr::new(b"\x1B]8;;https://example.com\x1B\\docs\x1B]8;;\x07 here");
+	/// assert_eq!(&*link.strip_ansi_escapes_cow(), "docs here");
+	///
+	/// // cursor movement and a character set selection
+	/// let raw = RawStr::new(b"\x1B[2K\x1B[1G\x1B(Bdone");
+	/// assert_eq!(&*raw.strip_ansi_escapes_cow(), "done");
+	///
+	/// let plain = RawStr::new(b"no escapes \xFF");
+	/// assert!(matches!(plain.strip_ansi_escapes_cow(), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn strip_ansi_escapes_cow(&self) -> Cow<'_, RawStr> {
+		let Some(first) = bytes::find_byte(&self.0, ESC) else {
+			return Cow::Borrowed(self);
+		};
+
+		let mut out = Vec::with_capacity(self.len());
+		let mut rest = &self.0[..];
+		let mut next = Some(first);
+		while let Some(i) = next {
+			out.extend_from_slice(&rest[..i]);
+			rest = &rest[i + escape_len(&rest[i..])..];
+			next = bytes::find_byte(rest, ESC);
+		}
+		out.extend_from_slice(rest);
+		Cow::Owned(RawString(out))
+	}
+
+	/// Returns an adapter that displays the string with everything a terminal could interpret
+	/// rendered as visible escapes, so that untrusted bytes can be printed safely.
+	///
+	/// Valid UTF-8 is written as is, except for:
+	/// - ASCII control characters and `DEL`, written as `\xNN`,
+	/// - C1 control characters and bidirectional formatting characters, written as `\u{NNNN}`,
+	/// - backslashes, written as `\\` so that the output is unambiguous.
+	///
+	/// Each byte of an invalid sequence is written as `\xNN`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let untrusted = RawStr::new(b"name\x1B]0;pwned\x07\xFF\xFE\xC3\xA9\\\n");
+	/// assert_eq!(
+	///     untrusted.escape_for_terminal().to_string(),
+	///     r"name\x1b]0;pwned\x07\xff\xfeé\\\x0a",
+	/// );
+	/// assert_eq!(RawStr::new("\u{9B}31m\u{202E}").escape_for_terminal().to_string(), r"\u{9b}31m\u{202e}");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn escape_for_terminal(&self) -> EscapeForTerminal<'_> {
+		EscapeForTerminal(self)
+	}
+}
+
+/// A [`Display`](fmt::Display) adapter escaping a [`RawStr`] for printing to a terminal.
+///
+/// This is created by [`RawStr::escape_for_terminal`].
+#[derive(Clone, Copy, Debug)]
+pub struct EscapeForTerminal<'a>(&'a RawStr);
+
+impl fmt::Display for EscapeForTerminal<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for chunk in self.0.0.utf8_chunks() {
+			let valid = chunk.valid();
+			// write runs of safe characters at once
+			let mut start = 0;
+			for (i, c) in valid.char_indices() {
+				if !is_unsafe(c) {
+					continue;
+				}
+				f.write_str(&valid[start..i])?;
+				match c {
+					'\\' => f.write_str("\\\\")?,
+					'\0'..='\x1F' | '\x7F' => write!(f, "\\x{:02x}", c as u32)?,
+					_ => write!(f, "\\u{{{:x}}}", c as u32)?,
+				}
+				start = i + c.len_utf8();
+			}
+			f.write_str(&valid[start..])?;
+			for b in chunk.invalid() {
+				write!(f, "\\x{b:02x}")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Returns `true` if `c` must be escaped before being written to a terminal.
+#[inline]
+fn is_unsafe(c: char) -> bool {
+	c == '\\'
+		|| c.is_control()
+		|| matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Returns the length of the escape sequence at the start of `s`, which starts with `ESC`.
+fn escape_len(s: &[u8]) -> usize {
+	let Some(&kind) = s.get(1) else {
+		return 1;
+	};
+	match kind {
+		b'[' => {
+			// parameter bytes, then intermediate bytes, then the final byte
+			let mut i = 2;
+			while s.get(i).is_some_and(|b| (0x30..=0x3F).contains(b)) {
+				i += 1;
+			}
+			while s.get(i).is_some_and(|b| (0x20..=0x2F).contains(b)) {
+				i += 1;
+			}
+			match s.get(i) {
+				Some(0x40..=0x7E) => i + 1,
+				_ => i,
+			}
+		}
+		b']' | b'P' | b'X' | b'^' | b'_' => {
+			let body = &s[2..];
+			match bytes::find_byte2(body, BEL, ESC) {
+				None => s.len(),
+				Some(i) if body[i] == BEL => 2 + i + 1,
+				Some(i) if body.get(i + 1) == Some(&b'\\') => 2 + i + 2,
+				// another escape sequence starts, cancelling this one
+				Some(i) => 2 + i,
+			}
+		}
+		0x20..=0x2F => {
+			let mut i = 2;
+			while s.get(i).is_some_and(|b| (0x20..=0x2F).contains(b)) {
+				i += 1;
+			}
+			match s.get(i) {
+				Some(0x30..=0x7E) => i + 1,
+				_ => i,
+			}
+		}
+		0x30..=0x7E => 2,
+		_ => 1,
+	}
+}
@@ -0,0 +1,246 @@
+// rawstring::transform
+
+use crate::{RawStr, RawString, bytes, utf8};
+
+impl RawStr {
+	/// Returns a new [`RawString`] made of the string repeated `n` times.
+	///
+	/// The result is allocated once, with its final size.
+	///
+	/// # Panics
+	/// Panics if the length of the result would overflow.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"-\xFF").repeat(3).as_ref(), b"-\xFF-\xFF-\xFF");
+	/// assert_eq!(RawStr::new("=").repeat(0).as_ref(), "");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn repeat(&self, n: usize) -> RawString {
+		RawString::from(self.0.repeat(n))
+	}
+
+	/// Returns a copy of the string with its bytes in reverse order.
+	///
+	/// This reverses multibyte sequences too, so it generally turns valid UTF-8 into garbage;
+	/// see [`reverse_chars`](Self::reverse_chars) for text.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\x01\x02\xFF").reverse_bytes().as_ref(), b"\xFF\x02\x01");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn reverse_bytes(&self) -> RawString {
+		let mut out = RawString::from(&self.0);
+		out.0.reverse();
+		out
+	}
+
+	/// Returns a copy of the string with its characters in reverse order.
+	///
+	/// Each valid character keeps its encoding, and each maximal invalid subpart
+	/// (as defined by [`Utf8Chunks`](std::str::Utf8Chunks)) is moved as a whole.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"ab\xC3\xA9\xF0\x9F\x92\xFF");
+	/// assert_eq!(raw.reverse_chars().as_ref(), b"\xFF\xF0\x9F\x92\xC3\xA9ba");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn reverse_chars(&self) -> RawString {
+		let mut out = RawString::from(&self.0);
+		out.as_mut().reverse_chars_in_place();
+		out
+	}
+
+	/// Reverses the order of the characters of the string in place.
+	///
+	/// See [`reverse_chars`](Self::reverse_chars).
+	pub fn reverse_chars_in_place(&mut self) {
+		// reversing each unit and then the whole string restores the bytes of each unit
+		let mut i = 0;
+		while let Some((_, len)) = utf8::decode_first(&self.0[i..]) {
+			self.0[i..i + len].reverse();
+			i += len;
+		}
+		self.0.reverse();
+	}
+}
+
+impl RawStr {
+	/// Returns a copy of the string with `\r\n` line endings converted to `\n`.
+	///
+	/// `lone_cr` decides whether a `\r` not followed by `\n` is also a line ending;
+	/// with [`LoneCr::Keep`], such bytes are left alone, which is safer for mixed binary data.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{LoneCr, RawStr};
+	/// let raw = RawStr::new(b"a\r\nb\r\xFF\r\n");
+	/// assert_eq!(raw.to_lf(LoneCr::Keep).as_ref(), b"a\nb\r\xFF\n");
+	/// assert_eq!(raw.to_lf(LoneCr::Convert).as_ref(), b"a\nb\n\xFF\n");
+	/// ```
+	#[must_use]
+	pub fn to_lf(&self, lone_cr: LoneCr) -> RawString {
+		let mut out = RawString::from(&self.0);
+		out.to_lf_in_place(lone_cr);
+		out
+	}
+
+	/// Returns a copy of the string with `\n` line endings converted to `\r\n`.
+	///
+	/// Existing `\r\n` endings are kept as they are. `lone_cr` decides whether
+	/// a `\r` not followed by `\n` is also a line ending, to be converted to `\r\n`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{LoneCr, RawStr};
+	/// let raw = RawStr::new(b"a\nb\r\n\xFF\rc");
+	/// assert_eq!(raw.to_crlf(LoneCr::Keep).as_ref(), b"a\r\nb\r\n\xFF\rc");
+	/// assert_eq!(raw.to_crlf(LoneCr::Convert).as_ref(), b"a\r\nb\r\n\xFF\r\nc");
+	/// ```
+	#[must_use]
+	pub fn to_crlf(&self, lone_cr: LoneCr) -> RawString {
+		let mut out = Vec::with_capacity(self.len());
+		let mut rest = &self.0[..];
+		while let Some(i) = bytes::find_byte2(rest, b'\r', b'\n') {
+			out.extend_from_slice(&rest[..i]);
+			let crlf = rest[i..].starts_with(b"\r\n");
+			if crlf || rest[i] == b'\n' || lone_cr == LoneCr::Convert {
+				out.extend_from_slice(b"\r\n");
+			} else {
+				out.push(b'\r');
+			}
+			rest = &rest[i + if crlf { 2 } else { 1 }..];
+		}
+		out.extend_from_slice(rest);
+		RawString::from(out)
+	}
+}
+
+impl RawString {
+	/// Converts `\r\n` line endings to `\n` in place, without allocating.
+	///
+	/// See [`RawStr::to_lf`].
+	pub fn to_lf_in_place(&mut self, lone_cr: LoneCr) {
+		let mut write = 0;
+		let mut read = 0;
+		while let Some(i) = bytes::find_byte(&self.0[read..], b'\r') {
+			let cr = read + i;
+			self.0.copy_within(read..cr, write);
+			write += cr - read;
+			// the `\r` of a `\r\n` is dropped, and its `\n` copied with the next run
+			if self.0.get(cr + 1) != Some(&b'\n') {
+				self.0[write] = if lone_cr == LoneCr::Convert { b'\n' } else { b'\r' };
+				write += 1;
+			}
+			read = cr + 1;
+		}
+		let len = self.0.len();
+		self.0.copy_within(read..len, write);
+		self.0.truncate(write + len - read);
+	}
+
+	/// Converts `\n` line endings to `\r\n` in place.
+	///
+	/// The buffer grows once by the number of inserted bytes,
+	/// and the string is then rewritten from the back.
+	///
+	/// See [`RawStr::to_crlf`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{LoneCr, RawString};
+	/// let mut raw = RawString::from(b"\r\ra\nb\r\n\xFF\rc\n".as_slice());
+	/// raw.to_crlf_in_place(LoneCr::Convert);
+	/// assert_eq!(raw.as_ref(), b"\r\n\r\na\r\nb\r\n\xFF\r\nc\r\n");
+	/// ```
+	pub fn to_crlf_in_place(&mut self, lone_cr: LoneCr) {
+		// count the bytes to insert first
+		let mut extra = 0;
+		let mut rest = &self.0[..];
+		while let Some(i) = bytes::find_byte2(rest, b'\r', b'\n') {
+			let crlf = rest[i..].starts_with(b"\r\n");
+			if !crlf && (rest[i] == b'\n' || lone_cr == LoneCr::Convert) {
+				extra += 1;
+			}
+			rest = &rest[i + if crlf { 2 } else { 1 }..];
+		}
+		if extra == 0 {
+			return;
+		}
+
+		let mut read = self.0.len();
+		self.0.resize(read + extra, 0);
+		let mut write = self.0.len();
+		// the bytes before `read` are untouched, and the ones after `write` are done
+		while write > read {
+			let i = bytes::rfind_byte2(&self.0[..read], b'\r', b'\n').expect("every insertion is at a line ending");
+			let run = read - (i + 1);
+			self.0.copy_within(i + 1..read, write - run);
+			write -= run;
+			let (ending, start): (&[u8], usize) = match self.0[i] {
+				b'\n' if i > 0 && self.0[i - 1] == b'\r' => (b"\r\n", i - 1),
+				b'\n' => (b"\r\n", i),
+				_ if lone_cr == LoneCr::Convert => (b"\r\n", i),
+				_ => (b"\r", i),
+			};
+			self.0[write - ending.len()..write].copy_from_slice(ending);
+			write -= ending.len();
+			read = start;
+		}
+	}
+}
+
+/// Whether a `\r` that is not followed by `\n` counts as a line ending.
+///
+/// Used by [`RawStr::to_lf`] and [`RawStr::to_crlf`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LoneCr {
+	/// A lone `\r` is not a line ending, and is left unchanged.
+	Keep,
+	/// A lone `\r` is a line ending, and is converted like the others.
+	Convert,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl RawStr {
+	/// Returns a copy of the string with its extended grapheme clusters in reverse order.
+	///
+	/// Clusters are split as with [`graphemes`](Self::graphemes),
+	/// so combining marks stay attached to their base character.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"ae\xCC\x81\xFF");
+	/// assert_eq!(raw.reverse_graphemes().as_ref(), b"\xFFe\xCC\x81a");
+	/// assert_eq!(raw.reverse_chars().as_ref(), b"\xFF\xCC\x81ea");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn reverse_graphemes(&self) -> RawString {
+		let mut out = RawString::from(&self.0);
+		out.as_mut().reverse_graphemes_in_place();
+		out
+	}
+
+	/// Reverses the order of the extended grapheme clusters of the string in place.
+	///
+	/// See [`reverse_graphemes`](Self::reverse_graphemes).
+	pub fn reverse_graphemes_in_place(&mut self) {
+		let lens: Vec<usize> = self.graphemes().map(|grapheme| grapheme.len()).collect();
+		let mut i = 0;
+		for len in lens {
+			self.0[i..i + len].reverse();
+			i += len;
+		}
+		self.0.reverse();
+	}
+}
@@ -0,0 +1,308 @@
+// rawstring::trim
+
+use crate::{
+	RawStr, RawString,
+	pattern::{RawPattern, RawSearcher},
+	utf8,
+};
+
+impl RawStr {
+	/// Returns the string with leading and trailing ASCII whitespace removed.
+	///
+	/// Whitespace is as defined by [`u8::is_ascii_whitespace`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\t value \xFF \r\n").trim(), b"value \xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim(&self) -> &RawStr {
+		RawStr::from_bytes(self.0.trim_ascii())
+	}
+
+	/// Returns the string with leading ASCII whitespace removed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"  \xFF ").trim_start(), b"\xFF ");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim_start(&self) -> &RawStr {
+		RawStr::from_bytes(self.0.trim_ascii_start())
+	}
+
+	/// Returns the string with trailing ASCII whitespace removed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b" \xFF\r\n").trim_end(), b" \xFF");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim_end(&self) -> &RawStr {
+		RawStr::from_bytes(self.0.trim_ascii_end())
+	}
+
+	/// Returns the string with leading and trailing Unicode whitespace removed.
+	///
+	/// Whitespace is as defined by [`char::is_whitespace`].
+	/// Invalid UTF-8 is never whitespace, so trimming stops at it.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xC2\xA0 name\xFF\xE3\x80\x80\n");
+	/// assert_eq!(raw.trim_unicode(), b"name\xFF");
+	/// assert_eq!(raw.trim(), b"\xC2\xA0 name\xFF\xE3\x80\x80");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim_unicode(&self) -> &RawStr {
+		self.trim_start_unicode().trim_end_unicode()
+	}
+
+	/// Returns the string with leading Unicode whitespace removed.
+	///
+	/// See [`trim_unicode`](Self::trim_unicode).
+	#[inline]
+	#[must_use]
+	pub fn trim_start_unicode(&self) -> &RawStr {
+		RawStr::from_bytes(&self.0[leading_whitespace_len(&self.0)..])
+	}
+
+	/// Returns the string with trailing Unicode whitespace removed.
+	///
+	/// See [`trim_unicode`](Self::trim_unicode).
+	#[inline]
+	#[must_use]
+	pub fn trim_end_unicode(&self) -> &RawStr {
+		RawStr::from_bytes(&self.0[..self.len() - trailing_whitespace_len(&self.0)])
+	}
+
+	/// Returns the string with all leading and trailing matches of `pattern` removed.
+	///
+	/// See [`RawPattern`] for the kinds of patterns accepted.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"//srv/\xFF//").trim_matches(b'/'), b"srv/\xFF");
+	/// assert_eq!(RawStr::new("xyxdatax").trim_matches("xy"), "xdatax");
+	/// assert_eq!(RawStr::new("123abc456").trim_matches(|c: char| c.is_ascii_digit()), "abc");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim_matches<P, M>(&self, pattern: P) -> &RawStr
+	where
+		P: RawPattern<M>
+	{
+		let mut searcher = pattern.into_searcher();
+		let start = leading_matches_len(&mut searcher, &self.0);
+		let end = self.len() - trailing_matches_len(&mut searcher, &self.0[start..]);
+		RawStr::from_bytes(&self.0[start..end])
+	}
+
+	/// Returns the string with all leading matches of `pattern` removed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\0\0\0\xFFdata").trim_start_matches(b'\0'), b"\xFFdata");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim_start_matches<P, M>(&self, pattern: P) -> &RawStr
+	where
+		P: RawPattern<M>
+	{
+		let start = leading_matches_len(&mut pattern.into_searcher(), &self.0);
+		RawStr::from_bytes(&self.0[start..])
+	}
+
+	/// Returns the string with all trailing matches of `pattern` removed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"name\xFF\0\0\0\0").trim_end_matches(b'\0'), b"name\xFF");
+	/// assert_eq!(RawStr::new("a\r\n\r\n").trim_end_matches("\r\n"), "a");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn trim_end_matches<P, M>(&self, pattern: P) -> &RawStr
+	where
+		P: RawPattern<M>
+	{
+		let end = self.len() - trailing_matches_len(&mut pattern.into_searcher(), &self.0);
+		RawStr::from_bytes(&self.0[..end])
+	}
+
+	/// Returns a copy of the string with leading and trailing ASCII whitespace removed
+	/// and every inner run of ASCII whitespace replaced by a single space.
+	///
+	/// Whitespace is as defined by [`u8::is_ascii_whitespace`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"  GET \t /index\xFF\r\n  HTTP/1.1\n");
+	/// assert_eq!(raw.collapse_whitespace().as_ref(), b"GET /index\xFF HTTP/1.1");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn collapse_whitespace(&self) -> RawString {
+		join_with_spaces(self.len(), self.split_ascii_whitespace())
+	}
+
+	/// Returns a copy of the string with leading and trailing Unicode whitespace removed
+	/// and every inner run of Unicode whitespace replaced by a single ASCII space.
+	///
+	/// Whitespace is as defined by [`char::is_whitespace`]; invalid UTF-8 is never whitespace.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xE3\x80\x80user\xC2\xA0\xC2\xA0logged \xFFin\n");
+	/// assert_eq!(raw.collapse_whitespace_unicode().as_ref(), b"user logged \xFFin");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn collapse_whitespace_unicode(&self) -> RawString {
+		join_with_spaces(self.len(), self.split_whitespace())
+	}
+}
+
+impl RawString {
+	/// Removes leading and trailing ASCII whitespace in place.
+	///
+	/// See [`RawStr::trim`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b" \xFF line\r\n".as_slice());
+	/// raw.trim_in_place();
+	/// assert_eq!(raw.as_ref(), b"\xFF line");
+	/// ```
+	#[inline]
+	pub fn trim_in_place(&mut self) {
+		self.trim_end_in_place();
+		self.trim_start_in_place();
+	}
+
+	/// Removes leading ASCII whitespace in place.
+	///
+	/// See [`RawStr::trim_start`].
+	#[inline]
+	pub fn trim_start_in_place(&mut self) {
+		let len = self.0.len() - self.0.trim_ascii_start().len();
+		self.0.drain(..len);
+	}
+
+	/// Removes trailing ASCII whitespace in place.
+	///
+	/// See [`RawStr::trim_end`].
+	#[inline]
+	pub fn trim_end_in_place(&mut self) {
+		let len = self.0.trim_ascii_end().len();
+		self.0.truncate(len);
+	}
+
+	/// Removes leading and trailing Unicode whitespace in place.
+	///
+	/// See [`RawStr::trim_unicode`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from("\u{A0}padded\u{A0}");
+	/// raw.trim_unicode_in_place();
+	/// assert_eq!(raw.as_ref(), "padded");
+	/// ```
+	#[inline]
+	pub fn trim_unicode_in_place(&mut self) {
+		self.trim_end_unicode_in_place();
+		self.trim_start_unicode_in_place();
+	}
+
+	/// Removes leading Unicode whitespace in place.
+	///
+	/// See [`RawStr::trim_start_unicode`].
+	#[inline]
+	pub fn trim_start_unicode_in_place(&mut self) {
+		let len = leading_whitespace_len(&self.0);
+		self.0.drain(..len);
+	}
+
+	/// Removes trailing Unicode whitespace in place.
+	///
+	/// See [`RawStr::trim_end_unicode`].
+	#[inline]
+	pub fn trim_end_unicode_in_place(&mut self) {
+		let len = self.0.len() - trailing_whitespace_len(&self.0);
+		self.0.truncate(len);
+	}
+}
+
+/// Concatenates `words` with a single space between each of them.
+fn join_with_spaces<'a, I>(capacity: usize, words: I) -> RawString
+where
+	I: Iterator<Item = &'a RawStr>
+{
+	let mut out = Vec::with_capacity(capacity);
+	for word in words {
+		if !out.is_empty() {
+			out.push(b' ');
+		}
+		out.extend_from_slice(word);
+	}
+	RawString(out)
+}
+
+/// Returns the length of the Unicode whitespace at the start of `bytes`.
+fn leading_whitespace_len(bytes: &[u8]) -> usize {
+	let mut len = 0;
+	while let Some((Some(c), n)) = utf8::decode_first(&bytes[len..]) {
+		if !c.is_whitespace() {
+			break;
+		}
+		len += n;
+	}
+	len
+}
+
+/// Returns the length of the Unicode whitespace at the end of `bytes`.
+fn trailing_whitespace_len(bytes: &[u8]) -> usize {
+	let mut len = 0;
+	while let Some((Some(c), n)) = utf8::decode_last(&bytes[..bytes.len() - len]) {
+		if !c.is_whitespace() {
+			break;
+		}
+		len += n;
+	}
+	len
+}
+
+/// Returns the length of the consecutive matches of `searcher` at the start of `bytes`.
+fn leading_matches_len<S: RawSearcher>(searcher: &mut S, bytes: &[u8]) -> usize {
+	let mut len = 0;
+	// an empty match would never advance
+	while let Some(n @ 1..) = searcher.match_prefix(&bytes[len..]) {
+		len += n;
+	}
+	len
+}
+
+/// Returns the length of the consecutive matches of `searcher` at the end of `bytes`.
+fn trailing_matches_len<S: RawSearcher>(searcher: &mut S, bytes: &[u8]) -> usize {
+	let mut len = 0;
+	while let Some(n @ 1..) = searcher.match_suffix(&bytes[..bytes.len() - len]) {
+		len += n;
+	}
+	len
+}
@@ -0,0 +1,113 @@
+// rawstring::utf16
+
+use crate::{RawStr, RawString, UNICODE_REPLACEMENT_CHARACTER, utf8};
+
+impl RawString {
+	/// Decodes UTF-16 into a [`RawString`], keeping unpaired surrogates.
+	///
+	/// Each unpaired surrogate is encoded as if it were a character, as done by
+	/// [WTF-8](https://simonsapin.github.io/wtf-8/), which makes the result invalid UTF-8
+	/// but lets [`RawStr::encode_utf16`] restore the original units exactly.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let units = [0x68, 0xD83D, 0xDE00, 0xD800, 0x69];
+	/// let raw = RawString::from_utf16(&units);
+	/// assert_eq!(raw.as_ref(), b"h\xF0\x9F\x98\x80\xED\xA0\x80i");
+	/// assert!(raw.as_ref().encode_utf16().eq(units));
+	/// ```
+	#[must_use]
+	pub fn from_utf16(units: &[u16]) -> Self {
+		let mut out = Vec::with_capacity(units.len());
+		for c in char::decode_utf16(units.iter().copied()) {
+			match c {
+				Ok(c) => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+				Err(e) => out.extend_from_slice(&utf8::encode_surrogate(e.unpaired_surrogate())),
+			}
+		}
+		Self(out)
+	}
+
+	/// Decodes UTF-16 into a [`RawString`],
+	/// replacing each unpaired surrogate with the replacement character (�).
+	///
+	/// The result is always valid UTF-8. See [`String::from_utf16_lossy`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let raw = RawString::from_utf16_lossy(&[0x68, 0xDC00, 0x69]);
+	/// assert_eq!(raw.as_ref(), "h�i");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn from_utf16_lossy(units: &[u16]) -> Self {
+		Self(String::from_utf16_lossy(units).into_bytes())
+	}
+}
+
+impl RawStr {
+	/// Returns an iterator over the string encoded as UTF-16.
+	///
+	/// Surrogates encoded as if they were characters, as produced by
+	/// [`RawString::from_utf16`], are turned back into their code unit.
+	/// Each other maximal invalid subpart is encoded as the replacement character (�).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let units: Vec<u16> = RawStr::new(b"a\xF0\x9F\x98\x80\xFF").encode_utf16().collect();
+	/// assert_eq!(units, [0x61, 0xD83D, 0xDE00, 0xFFFD]);
+	/// ```
+	#[inline]
+	pub fn encode_utf16(&self) -> EncodeUtf16<'_> {
+		EncodeUtf16 { bytes: &self.0, low: None }
+	}
+}
+
+/// An iterator over the UTF-16 code units of a string.
+///
+/// Created by [`RawStr::encode_utf16`].
+#[derive(Clone, Debug)]
+pub struct EncodeUtf16<'h> {
+	bytes: &'h [u8],
+	// the low surrogate of the last character, yet to be yielded
+	low: Option<u16>,
+}
+
+impl Iterator for EncodeUtf16<'_> {
+	type Item = u16;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(low) = self.low.take() {
+			return Some(low);
+		}
+		if let Some(unit) = utf8::decode_surrogate(self.bytes) {
+			self.bytes = &self.bytes[3..];
+			return Some(unit);
+		}
+
+		let (c, len) = utf8::decode_first(self.bytes)?;
+		self.bytes = &self.bytes[len..];
+		let mut buf = [0; 2];
+		match *c.unwrap_or(UNICODE_REPLACEMENT_CHARACTER).encode_utf16(&mut buf) {
+			[high, low] => {
+				self.low = Some(low);
+				Some(high)
+			}
+			[unit] => Some(unit),
+			_ => unreachable!("a character is one or two code units"),
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// each byte makes at most one code unit, and every three bytes at least one
+		let low = usize::from(self.low.is_some());
+		(self.bytes.len().div_ceil(3) + low, Some(self.bytes.len() + low))
+	}
+}
+
+impl std::iter::FusedIterator for EncodeUtf16<'_> {}
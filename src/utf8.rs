@@ -0,0 +1,80 @@
+// rawstring::utf8
+
+/// Returns `true` if `byte` is a UTF-8 continuation byte (`0b10xx_xxxx`).
+#[inline]
+pub(crate) const fn is_continuation(byte: u8) -> bool {
+	byte & 0xC0 == 0x80
+}
+
+/// Decodes the first UTF-8 unit of `bytes`.
+///
+/// A unit is either a valid character or a maximal invalid subpart,
+/// exactly as produced by [`Utf8Chunks`](std::str::Utf8Chunks).
+/// Returns the decoded character (or [`None`] for an invalid subpart)
+/// together with the length of the unit in bytes,
+/// or [`None`] if `bytes` is empty.
+#[inline]
+pub(crate) fn decode_first(bytes: &[u8]) -> Option<(Option<char>, usize)> {
+	let &first = bytes.first()?;
+	if first.is_ascii() {
+		return Some((Some(first as char), 1));
+	}
+
+	// a unit is never longer than four bytes
+	let head = &bytes[..bytes.len().min(4)];
+	let chunk = head.utf8_chunks().next()?;
+	match chunk.valid().chars().next() {
+		Some(c) => Some((Some(c), c.len_utf8())),
+		None => Some((None, chunk.invalid().len())),
+	}
+}
+
+/// Decodes the last UTF-8 unit of `bytes`.
+///
+/// This is the reverse of [`decode_first`]: the units produced by repeatedly
+/// decoding from the back are the same units produced by decoding from the front.
+#[inline]
+pub(crate) fn decode_last(bytes: &[u8]) -> Option<(Option<char>, usize)> {
+	let &last = bytes.last()?;
+	if last.is_ascii() {
+		return Some((Some(last as char), 1));
+	}
+
+	// forward decoding always starts a new unit at a non-continuation byte,
+	// so the last unit starts at the last such byte among the final four
+	let min = bytes.len().saturating_sub(4);
+	let Some(start) = (min..bytes.len()).rev().find(|&i| !is_continuation(bytes[i])) else {
+		// no lead byte can reach the final byte
+		return Some((None, 1));
+	};
+
+	let mut rest = &bytes[start..];
+	let mut unit = None;
+	while let Some(next @ (_, len)) = decode_first(rest) {
+		unit = Some(next);
+		rest = &rest[len..];
+	}
+	unit
+}
+
+/// Encodes a surrogate code point the way UTF-8 would encode any other code point,
+/// as done by WTF-8.
+#[inline]
+pub(crate) const fn encode_surrogate(unit: u16) -> [u8; 3] {
+	[
+		0xE0 | (unit >> 12) as u8,
+		0x80 | ((unit >> 6) & 0x3F) as u8,
+		0x80 | (unit & 0x3F) as u8,
+	]
+}
+
+/// Decodes a surrogate code point encoded by [`encode_surrogate`] at the start of `bytes`.
+#[inline]
+pub(crate) fn decode_surrogate(bytes: &[u8]) -> Option<u16> {
+	match *bytes {
+		[0xED, b1 @ 0xA0..=0xBF, b2 @ 0x80..=0xBF, ..] => {
+			Some(0xD000 | (u16::from(b1 & 0x3F) << 6) | u16::from(b2 & 0x3F))
+		}
+		_ => None,
+	}
+}
@@ -0,0 +1,99 @@
+// rawstring::width
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns the number of terminal columns the string takes up when displayed.
+	///
+	/// Valid UTF-8 is measured with [`UnicodeWidthStr::width`], so wide characters
+	/// take up two columns and combining marks none. Each maximal invalid subpart
+	/// takes up one column, as it is displayed as a single replacement character.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("abc").display_width(), 3);
+	/// assert_eq!(RawStr::new("日本").display_width(), 4);
+	/// assert_eq!(RawStr::new(b"e\xCC\x81\xFF\xFE").display_width(), 3);
+	/// ```
+	#[must_use]
+	pub fn display_width(&self) -> usize {
+		display_width(&self.0, 1)
+	}
+
+	/// Returns a copy of the string shortened to fit in `max_columns` terminal columns,
+	/// ending with `ellipsis` if anything was cut.
+	///
+	/// The string and the ellipsis are measured as with [`display_width`](Self::display_width),
+	/// except that each maximal invalid subpart counts as `invalid_width` columns,
+	/// to match how the caller renders invalid bytes.
+	/// The string is only cut between characters, with room left for the ellipsis.
+	/// If the ellipsis itself is wider than `max_columns`, only the ellipsis is returned.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFFfile name.txt");
+	/// assert_eq!(raw.truncate_display(8, "…", 1).as_ref(), b"\xFFfile n\xE2\x80\xA6");
+	/// assert_eq!(raw.truncate_display(20, "…", 1).as_ref(), raw);
+	///
+	/// // a wide character that does not fit is dropped whole
+	/// assert_eq!(RawStr::new("日本語").truncate_display(4, "...", 1).as_ref(), "...");
+	/// assert_eq!(RawStr::new("日本語").truncate_display(5, "...", 1).as_ref(), "日...");
+	///
+	/// // an emoji sequence is as wide as its display width says
+	/// let family = RawStr::new("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}");
+	/// assert_eq!(family.display_width(), 2);
+	/// assert_eq!(family.truncate_display(2, "…", 1).as_ref(), family);
+	/// ```
+	#[must_use]
+	pub fn truncate_display<B>(&self, max_columns: usize, ellipsis: &B, invalid_width: usize) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let ellipsis = ellipsis.as_ref();
+		if display_width(&self.0, invalid_width) <= max_columns {
+			return RawString::from(&self.0);
+		}
+
+		let budget = max_columns.saturating_sub(display_width(ellipsis, invalid_width));
+		// the width of the chunks before `offset`
+		let mut width = 0;
+		let mut offset = 0;
+		let end = 'cut: {
+			for chunk in self.0.utf8_chunks() {
+				let valid = chunk.valid();
+				// a prefix is measured whole, as the width of a string is not the sum of its characters
+				for (i, c) in valid.char_indices() {
+					if width + valid[..i + c.len_utf8()].width() > budget {
+						break 'cut offset + i;
+					}
+				}
+				width += valid.width();
+				offset += valid.len();
+				if !chunk.invalid().is_empty() {
+					width += invalid_width;
+					if width > budget {
+						break 'cut offset;
+					}
+					offset += chunk.invalid().len();
+				}
+			}
+			offset
+		};
+		let mut out = Vec::with_capacity(end + ellipsis.len());
+		out.extend_from_slice(&self.0[..end]);
+		out.extend_from_slice(ellipsis);
+		RawString::from(out)
+	}
+}
+
+/// Returns the display width of `bytes`, counting `invalid_width` columns for each invalid subpart.
+pub(crate) fn display_width(bytes: &[u8], invalid_width: usize) -> usize {
+	bytes
+		.utf8_chunks()
+		.map(|chunk| chunk.valid().width() + if chunk.invalid().is_empty() { 0 } else { invalid_width })
+		.sum()
+}